@@ -0,0 +1,217 @@
+use data::{Location, class::Class, time::{ClassTime, Day, Time, Times}};
+use helpers::parse_time;
+use iced::{Element, Length, widget::{button, column, pick_list, row, text, text_input}};
+
+/// Which of a class's schedules a meeting belongs to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Schedule {
+    Meetings,
+    OfficeHours,
+}
+
+#[derive(Debug, Clone)]
+pub enum Field {
+    Start,
+    End,
+    Campus,
+    Building,
+    Room,
+}
+
+#[derive(Debug, Clone)]
+pub enum FormMessage {
+    Name(String),
+    Professor(String),
+    AddMeeting(Schedule),
+    RemoveMeeting(Schedule, usize),
+    Day(Schedule, usize, Day),
+    Edit(Schedule, usize, Field, String),
+}
+
+/// One meeting as typed in, validated when the form is saved
+#[derive(Debug, Clone)]
+struct MeetingForm {
+    day: Day,
+    start: String,
+    end: String,
+    campus: String,
+    building: String,
+    room: String,
+}
+
+impl Default for MeetingForm {
+    fn default() -> Self {
+        MeetingForm {
+            day: Day::Monday,
+            start: String::new(),
+            end: String::new(),
+            campus: String::new(),
+            building: String::new(),
+            room: String::new(),
+        }
+    }
+}
+
+fn time_string(time: &Time) -> String {
+    if time.second == 0 { time.hm_string() } else { time.hms_string() }
+}
+
+impl From<&ClassTime> for MeetingForm {
+    fn from(time: &ClassTime) -> Self {
+        MeetingForm {
+            day: time.start.day,
+            start: time_string(&time.start),
+            end: time_string(&time.end),
+            campus: time.location.campus.clone(),
+            building: time.location.building.clone(),
+            room: time.location.room.clone(),
+        }
+    }
+}
+
+impl MeetingForm {
+    fn build(&self, what: &str) -> Result<ClassTime, String> {
+        let parse = |input: &str, which: &str| {
+            parse_time(input.trim()).map_err(|e| format!("{what} on {}, {which} time: {e}", self.day))
+        };
+
+        let (hour, minute, second) = parse(&self.start, "start")?;
+        let start = Time { day: self.day, hour, minute, second };
+
+        let (hour, minute, second) = parse(&self.end, "end")?;
+        let mut end = Time { day: self.day, hour, minute, second };
+        // ending earlier than the start means the meeting runs past midnight
+        if (end.hour, end.minute, end.second) < (start.hour, start.minute, start.second) {
+            end.day = self.day.tomorrow();
+        }
+
+        let location = Location { campus: self.campus.clone(), building: self.building.clone(), room: self.room.clone() };
+        return Ok((start, end, location).into());
+    }
+
+    fn view(&self, schedule: Schedule, i: usize) -> Element<'_, FormMessage> {
+        let field = |placeholder: &'static str, value: &str, field: Field| {
+            text_input(placeholder, value)
+                .on_input(move |input| FormMessage::Edit(schedule, i, field.clone(), input))
+                .width(Length::FillPortion(2))
+        };
+
+        row![
+            pick_list(Day::WEEK, Some(self.day), move |day| FormMessage::Day(schedule, i, day)).width(Length::FillPortion(3)),
+            field("Start (13:00)", &self.start, Field::Start),
+            field("End (2:15 PM)", &self.end, Field::End),
+            field("Campus", &self.campus, Field::Campus),
+            field("Building", &self.building, Field::Building),
+            field("Room", &self.room, Field::Room),
+            button("Remove").on_press(FormMessage::RemoveMeeting(schedule, i)),
+        ]
+        .spacing(6)
+        .into()
+    }
+}
+
+/// The add/edit class form
+#[derive(Debug, Clone, Default)]
+pub struct ClassForm {
+    /// Name of the class being edited, or `None` when adding one
+    pub editing: Option<String>,
+    name: String,
+    professor: String,
+    meetings: Vec<MeetingForm>,
+    office_hours: Vec<MeetingForm>,
+}
+
+fn meetings_of(times: &Times) -> Vec<MeetingForm> {
+    match times {
+        Times::Async => Vec::new(),
+        Times::At(times) => times.iter().map(MeetingForm::from).collect(),
+    }
+}
+
+impl ClassForm {
+    pub fn edit(class: &Class) -> ClassForm {
+        ClassForm {
+            editing: Some(class.get_name().clone()),
+            name: class.get_name().clone(),
+            professor: class.get_professor().clone(),
+            meetings: meetings_of(class.get_times()),
+            office_hours: meetings_of(class.get_office_hours()),
+        }
+    }
+
+    fn schedule(&mut self, schedule: Schedule) -> &mut Vec<MeetingForm> {
+        match schedule {
+            Schedule::Meetings => &mut self.meetings,
+            Schedule::OfficeHours => &mut self.office_hours,
+        }
+    }
+
+    pub fn update(&mut self, message: FormMessage) {
+        match message {
+            FormMessage::Name(name) => self.name = name,
+            FormMessage::Professor(professor) => self.professor = professor,
+            FormMessage::AddMeeting(schedule) => {
+                // new meetings usually share the time and place of the last one
+                let meetings = self.schedule(schedule);
+                let next = meetings.last().cloned().map(|m| MeetingForm { day: m.day.tomorrow(), ..m }).unwrap_or_default();
+                meetings.push(next);
+            },
+            FormMessage::RemoveMeeting(schedule, i) => { self.schedule(schedule).remove(i); },
+            FormMessage::Day(schedule, i, day) => self.schedule(schedule)[i].day = day,
+            FormMessage::Edit(schedule, i, field, value) => {
+                let meeting = &mut self.schedule(schedule)[i];
+                match field {
+                    Field::Start => meeting.start = value,
+                    Field::End => meeting.end = value,
+                    Field::Campus => meeting.campus = value,
+                    Field::Building => meeting.building = value,
+                    Field::Room => meeting.room = value,
+                }
+            },
+        }
+    }
+
+    /// Builds the class, keeping whatever the form doesn't cover (events, grace period) from `existing`.
+    pub fn build(&self, existing: Option<&Class>) -> Result<Class, String> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err("The class needs a name.".into());
+        }
+
+        let meetings = self.meetings.iter().map(|m| m.build("Meeting")).collect::<Result<Vec<_>, _>>()?;
+        let office_hours = self.office_hours.iter().map(|m| m.build("Office hours")).collect::<Result<Vec<_>, _>>()?;
+
+        let mut class = existing.cloned().unwrap_or_default();
+        class.set_name(name.to_string());
+        class.set_professor(self.professor.trim().to_string());
+        class.set_times(Times::from(meetings));
+        class.set_office_hours(Times::from(office_hours));
+
+        return Ok(class);
+    }
+
+    fn schedule_view<'a>(&'a self, title: &'a str, schedule: Schedule, meetings: &'a [MeetingForm]) -> Element<'a, FormMessage> {
+        let mut list = column![text(title).size(18)].spacing(6);
+        if meetings.is_empty() && schedule == Schedule::Meetings {
+            list = list.push(text("No meetings, so the class is asynchronous."));
+        }
+
+        for (i, meeting) in meetings.iter().enumerate() {
+            list = list.push(meeting.view(schedule, i));
+        }
+
+        list.push(button("Add").on_press(FormMessage::AddMeeting(schedule))).into()
+    }
+
+    pub fn view(&self) -> Element<'_, FormMessage> {
+        column![
+            text(if self.editing.is_some() { "Edit class" } else { "Add class" }).size(24),
+            text_input("Class name", &self.name).on_input(FormMessage::Name),
+            text_input("Professor", &self.professor).on_input(FormMessage::Professor),
+            self.schedule_view("Meetings", Schedule::Meetings, &self.meetings),
+            self.schedule_view("Office hours", Schedule::OfficeHours, &self.office_hours),
+        ]
+        .spacing(12)
+        .into()
+    }
+}
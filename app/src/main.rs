@@ -1,3 +1,279 @@
-fn main() {
-    println!("Hello, app!");
-}
\ No newline at end of file
+mod form;
+mod view;
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use data::{Config, Editor, editor::Preset};
+use form::{ClassForm, FormMessage};
+use helpers::{config::{load_config, save_config}, note::{get_latest_classnote, session_note_on, spawn_editor}};
+use iced::{Element, Length, Task, Theme, widget::{button, column, container, horizontal_rule, row, text, text_input}};
+
+/// Graphical front end to classnote, sharing the CLI's config
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+    /// Use this config file instead of the default one
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tab {
+    Schedule,
+    Courses,
+    Settings,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Tab(Tab),
+    Open(String),
+    Edit(String),
+    NewClass,
+    Form(FormMessage),
+    SaveClass,
+    DeleteClass,
+    CloseForm,
+    Root(String),
+    Editor(String),
+    SaveSettings,
+    Reload,
+}
+
+struct App {
+    config: Config,
+    config_path: Option<PathBuf>,
+    tab: Tab,
+    form: Option<ClassForm>,
+    root: String,
+    editor: String,
+    status: String,
+}
+
+impl App {
+    fn new(config_path: Option<PathBuf>) -> App {
+        let mut app = App {
+            config: Config::default(),
+            config_path,
+            tab: Tab::Schedule,
+            form: None,
+            root: String::new(),
+            editor: String::new(),
+            status: String::new(),
+        };
+        app.reload();
+
+        return app;
+    }
+
+    fn reload(&mut self) {
+        match load_config(self.config_path.as_ref()) {
+            Ok(Some(config)) => {
+                self.config = config;
+                self.status = "Loaded config.".into();
+            },
+            Ok(None) => self.status = "No config yet. Set a notes folder under Settings to get started.".into(),
+            Err(e) => self.status = e,
+        }
+
+        self.root = self.config.get_root().to_string_lossy().to_string();
+        self.editor = match self.config.get_editor() {
+            Editor::Custom { command, .. } => command.clone(),
+            editor => editor.get_program().clone(),
+        };
+    }
+
+    fn save(&mut self, done: &str) {
+        self.status = match save_config(&self.config, self.config_path.as_ref()) {
+            Ok(_) => done.into(),
+            Err(e) => format!("Unable to save config: {e:#}"),
+        };
+    }
+
+    fn open(&mut self, name: &str) {
+        let Some(class) = self.config.get_classes().iter().find(|c| c.get_name() == name) else {
+            return;
+        };
+
+        let note = match get_latest_classnote(&self.config, class) {
+            Some(note) => note,
+            None => match session_note_on(&self.config, class, chrono::Local::now().date_naive()) {
+                Ok((note, _)) => note,
+                Err(e) => {
+                    self.status = format!("Unable to create a note for {name}: {e:#}");
+                    return;
+                }
+            },
+        };
+
+        self.status = match spawn_editor(&self.config, &note, None) {
+            Ok(mut child) => {
+                // reap the editor once it exits
+                std::thread::spawn(move || child.wait());
+                format!("Opened \"{}\".", note.to_string_lossy())
+            },
+            Err(e) => e,
+        };
+    }
+
+    /// Folder of `course`'s notes, if there is one, for telling the user it stays behind
+    fn left_notes(&self, course: &str) -> Option<String> {
+        let dir = self.config.get_root().join(course);
+        dir.is_dir().then(|| dir.to_string_lossy().to_string())
+    }
+
+    /// Editor from the settings field, keeping an Obsidian setup that's already configured
+    fn editor_from_input(&self) -> Editor {
+        let input = self.editor.trim().to_string();
+        match (self.config.get_editor(), input.parse::<Preset>()) {
+            (Editor::Complex { .. }, Ok(Preset::Obsidian)) => self.config.get_editor().clone(),
+            (Editor::Custom { command, wait }, _) if *command == input => Editor::Custom { command: input, wait: *wait },
+            (_, Ok(_)) => Editor::Custom { command: input, wait: None },
+            _ if input.contains("{path}") => Editor::Custom { command: input, wait: None },
+            _ => Editor::Simple(input),
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Tab(tab) => {
+                self.tab = tab;
+                self.form = None;
+            },
+            Message::Open(name) => self.open(&name),
+            Message::Edit(name) => self.form = self.config.get_classes().iter().find(|c| *c.get_name() == name).map(ClassForm::edit),
+            Message::NewClass => self.form = Some(ClassForm::default()),
+            Message::Form(message) => if let Some(form) = self.form.as_mut() {
+                form.update(message);
+            },
+            Message::SaveClass => {
+                let Some(form) = self.form.as_ref() else {
+                    return;
+                };
+
+                let editing = form.editing.clone();
+                let existing = editing.as_deref().and_then(|name| self.config.get_classes().iter().find(|c| c.get_name() == name));
+                let class = match form.build(existing) {
+                    Ok(class) => class,
+                    Err(e) => {
+                        self.status = e;
+                        return;
+                    },
+                };
+
+                let renamed = editing.as_deref().is_none_or(|name| !name.eq_ignore_ascii_case(class.get_name()));
+                if renamed && self.config.get_classes().iter().any(|c| c.get_name().eq_ignore_ascii_case(class.get_name())) {
+                    self.status = format!("There's already a class named \"{}\".", class.get_name());
+                    return;
+                }
+
+                let moved_from = editing.as_deref().filter(|name| *name != class.get_name()).and_then(|name| self.left_notes(name));
+                let saved = match moved_from {
+                    Some(notes) => format!("Saved class. Its notes are still in \"{notes}\"; rename that folder to \"{}\" to keep them together.", class.get_name()),
+                    None => "Saved class.".into(),
+                };
+
+                match editing.as_deref().and_then(|name| self.config.get_class_mut(name)) {
+                    Some(slot) => *slot = class,
+                    None => self.config.add_class(class),
+                }
+
+                self.form = None;
+                self.save(&saved);
+            },
+            Message::DeleteClass => {
+                if let Some(name) = self.form.take().and_then(|f| f.editing) {
+                    let removed = match self.left_notes(&name) {
+                        Some(notes) => format!("Removed \"{name}\". Its notes were left in \"{notes}\"."),
+                        None => format!("Removed \"{name}\"."),
+                    };
+
+                    self.config.remove_class(&name);
+                    self.save(&removed);
+                }
+            },
+            Message::CloseForm => self.form = None,
+            Message::Root(root) => self.root = root,
+            Message::Editor(editor) => self.editor = editor,
+            Message::SaveSettings => {
+                let root = PathBuf::from(self.root.trim());
+                if !root.is_dir() {
+                    self.status = format!("\"{}\" isn't a folder.", root.to_string_lossy());
+                    return;
+                }
+
+                self.config.set_root(root);
+                self.config.set_editor(self.editor_from_input());
+                self.save("Saved settings.");
+            },
+            Message::Reload => {
+                self.form = None;
+                self.reload();
+            },
+        }
+    }
+
+    fn settings(&self) -> Element<'_, Message> {
+        let presets = Preset::ALL.iter().map(Preset::to_string).collect::<Vec<_>>().join(", ");
+
+        column![
+            text("Notes folder"),
+            text_input("/home/you/School", &self.root).on_input(Message::Root),
+            text("Editor"),
+            text_input("vim", &self.editor).on_input(Message::Editor),
+            text(format!("A program, a preset ({presets}), or a template like \"nvim +{{line}} {{path}}\".")).size(13),
+            button("Save").on_press(Message::SaveSettings),
+        ]
+        .spacing(8)
+        .into()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let tab = |label, tab| {
+            let style = if self.tab == tab { button::primary } else { button::secondary };
+            button(label).style(style).on_press(Message::Tab(tab))
+        };
+
+        let content = match (&self.form, self.tab) {
+            (Some(form), _) => {
+                let mut actions = row![
+                    button("Save").on_press(Message::SaveClass),
+                    button("Cancel").style(button::secondary).on_press(Message::CloseForm),
+                ].spacing(8);
+                if form.editing.is_some() {
+                    actions = actions.push(button("Delete").style(button::danger).on_press(Message::DeleteClass));
+                }
+
+                column![form.view().map(Message::Form), actions].spacing(12).into()
+            },
+            (None, Tab::Schedule) => view::schedule(&self.config),
+            (None, Tab::Courses) => view::courses(&self.config),
+            (None, Tab::Settings) => self.settings(),
+        };
+
+        column![
+            row![
+                tab("Schedule", Tab::Schedule),
+                tab("Courses", Tab::Courses),
+                tab("Settings", Tab::Settings),
+                iced::widget::horizontal_space(),
+                button("Reload").style(button::secondary).on_press(Message::Reload),
+            ].spacing(8),
+            horizontal_rule(1),
+            container(content).height(Length::Fill),
+            text(&self.status).size(13),
+        ]
+        .spacing(8)
+        .padding(12)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    let args = Args::parse();
+
+    iced::application("classnote", App::update, App::view)
+        .theme(|_| Theme::Light)
+        .run_with(move || (App::new(args.config), Task::none()))
+}
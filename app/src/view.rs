@@ -0,0 +1,102 @@
+use data::{Config, class::Class, time::{ClassTime, Day, Time, Times}};
+use helpers::note::{get_current_classes, get_latest_classnote};
+use iced::{Element, Length, Theme, widget::{button, column, container, row, scrollable, text}};
+
+use crate::Message;
+
+fn meeting_card<'a>(class: &Class, time: &ClassTime, in_session: bool) -> Element<'a, Message> {
+    let mut card = column![
+        text(class.get_name().clone()).size(16),
+        text(format!("{} - {}", time.start.hm_string(), time.end.hm_string())).size(13),
+    ];
+
+    let place = [&time.location.building, &time.location.room]
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if !place.is_empty() {
+        card = card.push(text(place).size(13));
+    }
+
+    let card = container(card).padding(6).width(Length::Fill);
+    if in_session {
+        return card.style(|theme: &Theme| container::Style {
+            background: Some(theme.palette().primary.scale_alpha(0.35).into()),
+            ..container::rounded_box(theme)
+        }).into();
+    }
+
+    card.style(container::rounded_box).into()
+}
+
+/// The week at a glance, Monday to Sunday, with the class in session highlighted
+pub fn schedule(config: &Config) -> Element<'_, Message> {
+    // the closest one, without asking like the CLI does when several tie
+    let current = get_current_classes(config, Time::now()).into_iter().next();
+
+    let days = Day::WEEK.iter().map(|&day| {
+        let mut meetings = config.get_classes()
+            .iter()
+            .flat_map(|class| match class.get_times() {
+                Times::Async => Vec::new(),
+                Times::At(times) => times.iter().filter(|t| t.start.day == day).map(|t| (class, t)).collect(),
+            })
+            .collect::<Vec<_>>();
+        meetings.sort_by_key(|(_, t)| t.start.get_hms());
+
+        let cards = meetings.into_iter().map(|(class, time)| {
+            let in_session = current.as_ref().is_some_and(|(c, t)| c.get_name() == class.get_name() && t == time);
+            meeting_card(class, time, in_session)
+        });
+
+        column![text(day.to_string()).size(18)]
+            .extend(cards)
+            .spacing(6)
+            .width(Length::Fill)
+            .into()
+    });
+
+    let mut view = column![row(days).spacing(8)].spacing(16);
+
+    let asynchronous = config.get_classes().iter().filter(|c| c.get_times().is_empty()).map(|c| c.get_name().clone()).collect::<Vec<_>>();
+    if !asynchronous.is_empty() {
+        view = view.push(text(format!("Asynchronous: {}", asynchronous.join(", "))));
+    }
+
+    scrollable(view).into()
+}
+
+/// Every class with its latest note
+pub fn courses(config: &Config) -> Element<'_, Message> {
+    let rows = config.get_classes().iter().map(|class| {
+        let latest = match get_latest_classnote(config, class) {
+            Some(note) => note.file_stem().unwrap_or_default().to_string_lossy().to_string(),
+            None => "No notes yet".into(),
+        };
+
+        container(
+            row![
+                column![
+                    text(class.get_name().clone()).size(18),
+                    text(class.get_professor().clone()).size(13),
+                ].width(Length::FillPortion(2)),
+                text(latest).width(Length::FillPortion(2)),
+                button("Open note").on_press(Message::Open(class.get_name().clone())),
+                button("Edit").on_press(Message::Edit(class.get_name().clone())),
+            ]
+            .spacing(8)
+            .align_y(iced::Alignment::Center)
+        )
+        .padding(8)
+        .style(container::rounded_box)
+        .into()
+    });
+
+    scrollable(
+        column(rows)
+            .push(button("Add class").on_press(Message::NewClass))
+            .spacing(8)
+    ).into()
+}
@@ -1,4 +1,9 @@
 pub use clap::{ Parser, Subcommand };
+use std::{path::PathBuf, str::FromStr};
+
+use chrono::{NaiveDate, NaiveDateTime};
+use export::Format;
+use helpers::{assignments::parse_due, output::OutputFormat, status::{DEFAULT_NEXT_TEMPLATE, DEFAULT_TEMPLATE}};
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
@@ -7,6 +12,15 @@ pub struct Cli {
     #[arg(short, long)]
     pub name: Option<String>,
 
+    /// Record the lecture into the session folder while the note is open
+    #[arg(short, long)]
+    pub record: bool,
+
+    /// How listing commands (list, now, next, status, search, questions, stats and attendance) print their
+    /// results: json, toml or text. Other commands ignore it, except export, where it takes html, pdf or epub.
+    #[arg(long, global = true)]
+    pub format: Option<FormatArg>,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -15,12 +29,80 @@ impl Cli {
     pub fn get_cli() -> Cli {
         return Cli::parse();
     }
+
+    /// How listing commands print. `Err` if `--format` names the wrong kind of format for the command.
+    pub fn output_format(&self) -> Result<OutputFormat, String> {
+        let exporting = matches!(self.command, Some(Commands::Export { .. }));
+
+        match self.format {
+            None => Ok(OutputFormat::Text),
+            Some(FormatArg::Output(format)) if exporting => Err(format!("Export writes html, pdf or epub, not {format}.")),
+            Some(FormatArg::Output(format)) => Ok(format),
+            Some(FormatArg::Document(_)) if exporting => Ok(OutputFormat::Text),
+            Some(FormatArg::Document(format)) => Err(format!("--format {format} only applies to export.")),
+        }
+    }
+
+    /// What `export` writes: `--to`, else `--format`, else HTML
+    pub fn document_format(&self) -> Format {
+        match (&self.command, self.format) {
+            (Some(Commands::Export { to: Some(to), .. }), _) => *to,
+            (_, Some(FormatArg::Document(format))) => format,
+            _ => Format::Html,
+        }
+    }
+}
+
+/// Value of the global `--format`: an output format for listing commands, or a document format for `export`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FormatArg {
+    Output(OutputFormat),
+    Document(Format),
+}
+
+impl FromStr for FormatArg {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(format) = s.parse::<OutputFormat>() {
+            return Ok(FormatArg::Output(format));
+        }
+
+        match s.parse::<Format>() {
+            Ok(format) => Ok(FormatArg::Document(format)),
+            Err(_) => Err(format!("Unknown format \"{s}\" (expected json, toml or text, or html, pdf or epub for export)")),
+        }
+    }
 }
 
 #[derive(Subcommand)]
 pub enum Commands {
     /// Lists available classes
     List,
+
+    /// Shows the class in session and what's due soon for it
+    Now,
+
+    /// Shows when and where the next class meets
+    Next,
+
+    /// Prints a one-line summary of the class in session or the next one, for status bars
+    Status {
+        /// Line shown during a class, with {class}, {professor}, {campus}, {building}, {room}, {start}, {end} and {left}
+        #[arg(short, long, default_value = DEFAULT_TEMPLATE)]
+        template: String,
+
+        /// Line shown between classes, with the same fields
+        #[arg(short, long, default_value = DEFAULT_NEXT_TEMPLATE)]
+        next_template: String,
+
+        /// Keep running, printing a new line whenever the status changes
+        #[arg(short, long)]
+        watch: bool,
+    },
+
+    /// Opens a full-screen dashboard of today's classes, recent notes, open questions and assignments
+    Tui,
+
     /// Updates existing class information
     Update,
 
@@ -34,5 +116,233 @@ pub enum Commands {
     Remove {
         /// Class name
         name: String
-    }
-}
\ No newline at end of file
+    },
+
+    /// Searches all notes for a phrase (case-insensitive)
+    Search {
+        /// Text to search for
+        query: String,
+
+        /// Only search notes of this course
+        #[arg(short, long)]
+        course: Option<String>,
+
+        /// Only search sessions on or after this date (YYYY-MM-DD)
+        #[arg(long)]
+        from: Option<NaiveDate>,
+
+        /// Only search sessions on or before this date (YYYY-MM-DD)
+        #[arg(long)]
+        to: Option<NaiveDate>,
+    },
+
+    /// Creates the session note of every class under way, for running from cron every few minutes
+    Tick,
+
+    /// Runs in the background, sending desktop notifications before classes and office hours and creating each session's note when it starts
+    Daemon {
+        /// Minutes ahead to remind (defaults to the config's, or 10)
+        #[arg(short, long)]
+        before: Option<u32>,
+    },
+
+    /// Lists open questions: items under a Questions heading and unchecked tasks
+    Questions {
+        /// Only list questions of this course
+        #[arg(short, long)]
+        course: Option<String>,
+    },
+
+    /// Reports note-taking habits per course: sessions, note length, words and open questions per week, longest gaps
+    Stats {
+        /// Only report this course
+        #[arg(short, long)]
+        course: Option<String>,
+    },
+
+    /// Compares a course's meetings this semester with its session notes, offering placeholders for missed ones
+    Attendance {
+        /// Course name
+        course: String,
+
+        /// Create placeholder notes for missed sessions without asking
+        #[arg(short, long)]
+        create: bool,
+    },
+
+    /// Compiles the Review and Summary sections of a course's notes into one study guide
+    Review {
+        /// Course name
+        course: String,
+
+        /// First session date to include (YYYY-MM-DD)
+        #[arg(long)]
+        from: Option<NaiveDate>,
+
+        /// Last session date to include (YYYY-MM-DD)
+        #[arg(long)]
+        to: Option<NaiveDate>,
+
+        /// Include whole notes instead of only their review sections
+        #[arg(short, long)]
+        whole: bool,
+
+        /// Where to write the guide (defaults to the course folder)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Exports all of a course's notes as a single HTML, PDF or EPUB document
+    Export {
+        /// Course name
+        course: String,
+
+        /// Output format (html, pdf or epub), also accepted as --format [default: html]
+        #[arg(short = 'f', long)]
+        to: Option<Format>,
+
+        /// Where to write the document (defaults to the course folder)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Appends a timestamped bookmark to the note of the class in session
+    Mark {
+        /// Text of the bookmark
+        text: String,
+    },
+
+    /// Copies files into a session folder and links them in its note
+    Attach {
+        /// Files to attach
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+
+        /// Move the files instead of copying them
+        #[arg(short, long)]
+        r#move: bool,
+
+        /// Course to attach to (defaults to the class in session)
+        #[arg(short, long)]
+        course: Option<String>,
+
+        /// Session of the course, by folder name, class number or date (defaults to the latest)
+        #[arg(short, long, requires = "course")]
+        session: Option<String>,
+    },
+
+    /// Lists exams, deadlines and other events coming up
+    Upcoming {
+        /// Only list events of this course
+        #[arg(short, long)]
+        course: Option<String>,
+
+        /// Only list events in the next this many days
+        #[arg(short, long)]
+        days: Option<i64>,
+    },
+
+    /// Manages a course's exams, deadlines and other dated events
+    Event {
+        #[command(subcommand)]
+        command: EventCommands,
+    },
+
+    /// Tracks homework and other assignments
+    #[command(visible_alias = "hw")]
+    Assignment {
+        #[command(subcommand)]
+        command: AssignmentCommands,
+    },
+
+    /// Regenerates the Obsidian Maps of Content and session links of one or all courses
+    Moc {
+        /// Course name (defaults to every course)
+        course: Option<String>,
+    },
+
+    /// Manages git version control of the notes folder
+    Git {
+        #[command(subcommand)]
+        command: GitCommands,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum AssignmentCommands {
+    /// Adds an assignment, linked to the latest note of its course
+    Add {
+        /// What the assignment is
+        title: String,
+
+        /// Due date: YYYY-MM-DD, "YYYY-MM-DD HH:MM", today or tomorrow
+        #[arg(short, long, value_parser = parse_due)]
+        due: NaiveDateTime,
+
+        /// Course it's for (defaults to the class in session)
+        #[arg(short, long)]
+        course: Option<String>,
+    },
+
+    /// Lists assignments by due date
+    List {
+        /// Only list assignments of this course
+        #[arg(short, long)]
+        course: Option<String>,
+
+        /// Include completed assignments
+        #[arg(short, long)]
+        all: bool,
+    },
+
+    /// Marks an assignment as done
+    Complete {
+        /// Number of the assignment, as shown by `list`
+        id: u32,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum EventCommands {
+    /// Adds an event to a course
+    Add {
+        /// Course name
+        course: String,
+
+        /// What's happening, e.g. Midterm
+        title: String,
+
+        /// When: YYYY-MM-DD or "YYYY-MM-DD HH:MM"
+        date: String,
+    },
+
+    /// Removes every event of a course with this title
+    Remove {
+        /// Course name
+        course: String,
+
+        /// Title of the event
+        title: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum GitCommands {
+    /// Enables git integration and initializes a repository in the notes folder
+    Init {
+        /// URL or path of a remote to sync with
+        #[arg(short, long)]
+        remote: Option<String>,
+
+        /// Pull from the remote after each commit
+        #[arg(long)]
+        pull: bool,
+
+        /// Push to the remote after each commit
+        #[arg(long)]
+        push: bool,
+    },
+
+    /// Commits all notes and syncs with the remote
+    Sync,
+}
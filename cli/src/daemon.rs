@@ -0,0 +1,141 @@
+use std::{sync::{Arc, mpsc::{self, Sender}}, time::Duration};
+
+use chrono::{Local, Timelike};
+use data::{Config, notifications::Notifications, time::Time};
+use helpers::{config::ConfigWatcher, reminders::{Reminder, due_reminders}, tick::print_tick};
+use notify_rust::Notification;
+
+// only freedesktop notification servers report back which action was clicked
+#[cfg(all(unix, not(target_os = "macos")))]
+fn open_note_of(config: &Config, reminder: &Reminder, date: chrono::NaiveDate) -> Option<std::process::Child> {
+    use helpers::note::{get_latest_classnote, session_note_on, spawn_editor};
+    use helpers::reminders::Slot;
+
+    // classes get the note of the session about to start, office hours the latest one there is
+    let latest = match reminder.slot {
+        Slot::Class => None,
+        Slot::OfficeHours => get_latest_classnote(config, &reminder.class),
+    };
+
+    let note = match latest {
+        Some(note) => note,
+        None => match session_note_on(config, &reminder.class, date) {
+            Ok((note, _)) => note,
+            Err(e) => {
+                eprintln!("Unable to create a note for {}: {e:#}", reminder.class.get_name());
+                return None;
+            }
+        },
+    };
+
+    match spawn_editor(config, &note, None) {
+        Ok(child) => return Some(child),
+        Err(e) => {
+            eprintln!("{e}");
+            return None;
+        },
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+type Sent = (notify_rust::NotificationHandle, Arc<Config>, Reminder, chrono::NaiveDate);
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+type Sent = ();
+
+/// Starts the thread waiting on sent notifications, opening the note of any that's clicked. Waiting blocks until a
+/// notification is closed, so they're waited on one after another in the order they were sent.
+fn handle_clicks() -> Sender<Sent> {
+    let (sender, receiver) = mpsc::channel::<Sent>();
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    std::thread::spawn(move || {
+        let mut editors = Vec::new();
+        for (handle, config, reminder, date) in receiver {
+            handle.wait_for_action(|action| {
+                if matches!(action, "default" | "open") {
+                    editors.extend(open_note_of(&config, &reminder, date));
+                }
+            });
+            // reap editors that were closed so they don't linger as zombies
+            editors.retain_mut(|editor| !matches!(editor.try_wait(), Ok(Some(_))));
+        }
+    });
+    #[cfg(not(all(unix, not(target_os = "macos"))))]
+    drop(receiver);
+
+    return sender;
+}
+
+#[cfg_attr(not(all(unix, not(target_os = "macos"))), allow(unused_variables))]
+fn notify(config: &Arc<Config>, reminder: Reminder, minutes: u32, now: Time, clicks: &Sender<Sent>) {
+    let mut notification = Notification::new();
+    notification
+        .appname("classnote")
+        .summary(&reminder.summary(minutes))
+        .body(&reminder.body());
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    notification.action("default", "Open note").action("open", "Open note");
+
+    match notification.show() {
+        #[cfg(all(unix, not(target_os = "macos")))]
+        Ok(handle) => {
+            // dated when sent, since the click may come after midnight
+            let date = reminder.date(now, Local::now().date_naive());
+            // the handler only stops with the daemon, so sending can't fail
+            let _ = clicks.send((handle, config.clone(), reminder, date));
+        },
+        #[cfg(not(all(unix, not(target_os = "macos"))))]
+        Ok(_) => {},
+        Err(e) => eprintln!("Unable to send reminder for {}: {e}", reminder.class.get_name()),
+    }
+}
+
+// `before` from the command line wins over the config's
+fn settings(config: &Config, before: Option<u32>) -> Notifications {
+    let mut settings = config.get_notifications().copied().unwrap_or_default();
+    if let Some(before) = before {
+        settings.before = before;
+    }
+
+    return settings;
+}
+
+/// Sends a notification ahead of every meeting and office-hours slot, and creates each session's note once it starts,
+/// until interrupted. Edits to the config file are picked up as they're made.
+pub fn run(config: Config, before: Option<u32>) {
+    let settings = settings(&config, before);
+    println!("Reminding {} minutes before classes{}. Press Ctrl+C to stop.",
+        settings.before,
+        if settings.office_hours { " and office hours" } else { "" }
+    );
+
+    remind(Arc::new(config), before);
+}
+
+fn remind(mut config: Arc<Config>, before: Option<u32>) {
+    let mut watcher = ConfigWatcher::new(None);
+    let mut last = Time::now();
+    let clicks = handle_clicks();
+
+    loop {
+        // reminders are to the minute, so there's no need to wake more often
+        let into_minute = Local::now().second() as u64;
+        std::thread::sleep(Duration::from_secs(60 - into_minute.min(59)));
+
+        if let Some(reloaded) = watcher.reload() {
+            config = Arc::new(reloaded);
+        }
+
+        let now = Time::now();
+        let settings = settings(&config, before);
+        for reminder in due_reminders(&config, last, now, &settings) {
+            notify(&config, reminder, settings.before, now, &clicks);
+        }
+
+        print_tick(&config);
+
+        last = now;
+    }
+}
@@ -1,13 +1,42 @@
 mod cli;
+mod daemon;
+mod tui;
 
-use cli::{ Cli, Commands };
+use cli::{ AssignmentCommands, Cli, Commands, EventCommands, GitCommands };
+use export::{default_export_path, export};
+use data::{assignment::Status, time::Time};
 use helpers::{
-    config::{get_config_file, read_or_init_config},
-    note::open_note
+    assignments::{add_assignment, complete_assignment, print_assignments, read_assignments},
+    attach::{attach, target_note},
+    attendance::{attendance, create_placeholders, print_attendance},
+    config::{get_config_file, read_or_init_config, save_config},
+    events::{parse_event, print_upcoming},
+    git::{commit_and_sync, init_repo, report_sync},
+    io::{Prompter, Stdin},
+    mark::add_mark,
+    moc::link_course,
+    note::{open_latest_note, open_note},
+    now::{next, now, print_next, print_now},
+    output::{ClassList, OutputFormat, QuestionList, SearchResults, StatsList},
+    questions::{all_questions, print_questions},
+    review::{build_review, default_review_path},
+    search::{SearchFilter, print_hits, search},
+    session::{find_course, get_courses},
+    stats::{print_stats, stats},
+    status::{Templates, print_status, watch_status},
+    tick::print_tick
 };
 
 fn main() {
     let args = Cli::get_cli();
+    let format = match args.output_format() {
+        Ok(format) => format,
+        Err(e) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+    let document = args.document_format();
 
     // if there's no custom config path
     let mut config_file = get_config_file(Default::default());
@@ -18,12 +47,223 @@ fn main() {
     };
     
     match args.command {
-        None => open_note(config),
-        Some(Commands::List) => config.print_available_classes(),
+        None => match args.name {
+            Some(name) => match config.get_classes().iter().find(|c| c.get_name().eq_ignore_ascii_case(&name)) {
+                Some(class) => open_latest_note(&config, class, args.record),
+                None => eprintln!("No class named \"{name}\" found.")
+            },
+            None => open_note(config, args.record),
+        },
+        Some(Commands::List) => format.emit(&ClassList { classes: config.get_classes() }, || config.print_available_classes()),
+        Some(Commands::Now) => {
+            let now = now(&config, Time::now());
+            format.emit(&now, || print_now(&config, &now));
+        },
+        Some(Commands::Next) => {
+            let next = next(&config, Time::now());
+            format.emit(&next, || print_next(&next));
+        },
+        Some(Commands::Status { template, next_template, watch }) => {
+            let templates = Templates { now: template, next: next_template };
+            if watch {
+                watch_status(config, None, &templates, format);
+            } else {
+                print_status(&config, &templates, format);
+            }
+        },
+        Some(Commands::Tick) => print_tick(&config),
+        Some(Commands::Daemon { before }) => daemon::run(config, before),
+        Some(Commands::Questions { course }) => {
+            let questions = all_questions(&config, course.as_deref());
+            format.emit(&QuestionList { questions: &questions }, || print_questions(&questions));
+        },
+        Some(Commands::Stats { course }) => {
+            let stats = stats(&config, course.as_deref(), chrono::Local::now().date_naive());
+            format.emit(&StatsList { courses: &stats }, || print_stats(&stats));
+        },
+        Some(Commands::Attendance { course, create }) => {
+            let Some(class) = config.get_classes().iter().find(|c| c.get_name().eq_ignore_ascii_case(&course)) else {
+                eprintln!("No class named \"{course}\" found.");
+                return;
+            };
+
+            let Some(semester) = config.get_semester() else {
+                eprintln!("No semester set. Add its dates to the config, like:\n\n[semester]\nstart = \"2026-08-24\"\nend = \"2026-12-11\"\nholidays = [{{ date = \"2026-11-26\", until = \"2026-11-27\", name = \"Thanksgiving\" }}]");
+                return;
+            };
+
+            let attendance = attendance(&config, class, semester, chrono::Local::now().date_naive());
+            format.emit(&attendance, || print_attendance(&attendance));
+
+            if attendance.missed.is_empty() { return; }
+            // other formats are for scripts, which can pass --create
+            if create || (format == OutputFormat::Text && Stdin.confirm("Create placeholder notes for the missed sessions? [y/N]: ", false)) {
+                match create_placeholders(&config, class, &attendance.missed) {
+                    Ok(notes) => println!("Created {} placeholder notes.", notes.len()),
+                    Err(e) => eprintln!("{e}"),
+                }
+            }
+        },
+        Some(Commands::Tui) => if let Err(e) = tui::run(&config) {
+            eprintln!("Dashboard error: {e:#}");
+        },
         Some(Commands::Update) => todo!(),
-        Some(Commands::Add { name }) => todo!(),
-        Some(Commands::Remove { name }) => todo!(),
-    }
-}
+        Some(Commands::Add { .. }) => todo!(),
+        Some(Commands::Remove { .. }) => todo!(),
+        Some(Commands::Search { query, course, from, to }) => {
+            let hits = search(&config, &query, &SearchFilter { course, from, to });
+            format.emit(&SearchResults { query: &query, hits: &hits }, || print_hits(&hits));
+        },
+        Some(Commands::Review { course, from, to, whole, output }) => {
+            let Some(course) = find_course(&config, &course) else {
+                eprintln!("No notes found for \"{course}\".");
+                return;
+            };
+
+            let output = output.unwrap_or_else(|| default_review_path(&config, &course, from, to));
+            let guide = build_review(&config, &course, from, to, whole, &output);
+
+            match std::fs::write(&output, guide) {
+                Ok(_) => println!("Wrote review guide to \"{}\".", output.to_string_lossy()),
+                Err(e) => eprintln!("Unable to write review guide to \"{}\": {e:#}", output.to_string_lossy())
+            }
+        },
+        Some(Commands::Export { course, output, .. }) => {
+            let Some(course) = find_course(&config, &course) else {
+                eprintln!("No notes found for \"{course}\".");
+                return;
+            };
+
+            let output = output.unwrap_or_else(|| default_export_path(&config, &course, document));
+            match export(&config, &course, document, &output) {
+                Ok(_) => println!("Exported {course} to \"{}\".", output.to_string_lossy()),
+                Err(e) => eprintln!("Unable to export {course} to \"{}\": {e:#}", output.to_string_lossy())
+            }
+        },
+        Some(Commands::Mark { text }) => match add_mark(&config, &text) {
+            Ok(note) => println!("Marked \"{text}\" in \"{}\".", note.to_string_lossy()),
+            Err(e) => eprintln!("Unable to add bookmark: {e}")
+        },
+        Some(Commands::Attach { files, r#move, course, session }) => {
+            let note = match target_note(&config, course.as_deref(), session.as_deref()) {
+                Ok(note) => note,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return;
+                }
+            };
+
+            if let Err(e) = attach(&config, &note, &files, r#move) {
+                eprintln!("Unable to attach files to \"{}\": {e:#}", note.to_string_lossy());
+            }
+        },
+        Some(Commands::Upcoming { course, days }) => print_upcoming(&config, course.as_deref(), days),
+        Some(Commands::Event { command: EventCommands::Add { course, title, date } }) => {
+            let event = match parse_event(title, &date) {
+                Ok(event) => event,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return;
+                }
+            };
 
+            let Some(class) = config.get_class_mut(&course) else {
+                eprintln!("No class named \"{course}\" found.");
+                return;
+            };
 
+            let added = format!("Added {event} to {}.", class.get_name());
+            class.add_event(event);
+            match save_config(&config, None) {
+                Ok(_) => println!("{added}"),
+                Err(e) => eprintln!("Unable to save config: {e:#}")
+            }
+        },
+        Some(Commands::Event { command: EventCommands::Remove { course, title } }) => {
+            let Some(class) = config.get_class_mut(&course) else {
+                eprintln!("No class named \"{course}\" found.");
+                return;
+            };
+
+            if class.remove_event(&title) == 0 {
+                eprintln!("{} has no event titled \"{title}\".", class.get_name());
+                return;
+            }
+
+            match save_config(&config, None) {
+                Ok(_) => println!("Removed \"{title}\"."),
+                Err(e) => eprintln!("Unable to save config: {e:#}")
+            }
+        },
+        Some(Commands::Assignment { command: AssignmentCommands::Add { title, due, course } }) => match add_assignment(&config, title, course.as_deref(), due) {
+            Ok(added) => println!("Added #{} to {}, due {}.", added.id, added.course, added.due.format("%a %Y-%m-%d %H:%M")),
+            Err(e) => eprintln!("{e}")
+        },
+        Some(Commands::Assignment { command: AssignmentCommands::List { course, all } }) => match read_assignments(&config) {
+            Ok(assignments) => print_assignments(
+                &config.get_root(),
+                &assignments
+                    .sorted()
+                    .into_iter()
+                    .filter(|a| all || a.status == Status::Todo)
+                    .filter(|a| course.as_ref().is_none_or(|c| a.course.eq_ignore_ascii_case(c)))
+                    .collect::<Vec<_>>()
+            ),
+            Err(e) => eprintln!("{e}")
+        },
+        Some(Commands::Assignment { command: AssignmentCommands::Complete { id } }) => match complete_assignment(&config, id) {
+            Ok(done) => println!("Completed #{id}: {}.", done.title),
+            Err(e) => eprintln!("{e}")
+        },
+        Some(Commands::Moc { course }) => {
+            if config.get_editor().obsidian().is_none() {
+                eprintln!("Maps of Content are only kept when notes are opened in Obsidian.");
+                return;
+            }
+
+            let courses = match course {
+                Some(name) => match find_course(&config, &name) {
+                    Some(course) => vec![course],
+                    None => {
+                        eprintln!("No notes found for \"{name}\".");
+                        return;
+                    }
+                },
+                None => get_courses(&config),
+            };
+
+            for course in courses {
+                match link_course(&config, &course) {
+                    Ok(_) => println!("Updated links of {course}."),
+                    Err(e) => eprintln!("Unable to update links of {course}: {e:#}")
+                }
+            }
+        },
+        Some(Commands::Git { command: GitCommands::Init { remote, pull, push } }) => {
+            let mut git = config.get_git().cloned().unwrap_or_default();
+            git.remote = remote.or(git.remote);
+            git.pull |= pull;
+            git.push |= push;
+
+            if let Err(e) = init_repo(&config.get_root(), &git) {
+                eprintln!("Unable to initialize repository: {e}");
+                return;
+            }
+
+            config.set_git(Some(git));
+            match save_config(&config, None) {
+                Ok(_) => println!("Enabled git integration in \"{}\".", config.get_root().to_string_lossy()),
+                Err(e) => eprintln!("Unable to save config: {e:#}")
+            }
+        },
+        Some(Commands::Git { command: GitCommands::Sync }) => {
+            let Some(git) = config.get_git() else {
+                eprintln!("Git integration is disabled. Run `classnote git init` to enable it.");
+                return;
+            };
+
+            let root = config.get_root();
+            report_sync(&root, "Sync notes", commit_and_sync(&root, &root, "Sync notes", git));
+        },
+    }
+}
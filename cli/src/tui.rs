@@ -0,0 +1,247 @@
+use std::{cmp::Reverse, io, time::Duration};
+
+use chrono::Local;
+use data::{Config, assignment::{Assignment, Status}, time::{ClassTime, Time, Times}};
+use helpers::{
+    assignments::{DUE_SOON_DAYS, describe_due, read_assignments},
+    note::{edit_note, get_current_class, get_current_classes, get_or_create_classnote},
+    now::{next_class, short_duration},
+    questions::{Question, course_questions},
+    session::{Session, get_sessions},
+};
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Block, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+/// Notes listed per course
+const RECENT_NOTES: usize = 3;
+
+fn place(time: &ClassTime) -> String {
+    [&time.location.building, &time.location.room]
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+struct Dashboard<'a> {
+    config: &'a Config,
+    notes: Vec<Session>,
+    questions: Vec<Question>,
+    assignments: Vec<Assignment>,
+    selected: ListState,
+    status: String,
+}
+
+impl<'a> Dashboard<'a> {
+    fn new(config: &'a Config) -> Dashboard<'a> {
+        let mut dashboard = Dashboard {
+            config,
+            notes: Vec::new(),
+            questions: Vec::new(),
+            assignments: Vec::new(),
+            selected: ListState::default(),
+            status: String::new(),
+        };
+        dashboard.refresh();
+
+        return dashboard;
+    }
+
+    /// Rereads notes and assignments from disk
+    fn refresh(&mut self) {
+        let root = self.config.get_root();
+
+        self.notes = self.config.get_classes()
+            .iter()
+            .flat_map(|class| {
+                let sessions = get_sessions(&root, class.get_name()).into_iter().filter(|s| s.note.is_file()).collect::<Vec<_>>();
+                sessions.into_iter().rev().take(RECENT_NOTES)
+            })
+            .collect();
+
+        self.questions = self.config.get_classes().iter().flat_map(|class| course_questions(self.config, class.get_name())).collect();
+        self.questions.sort_by_key(|q| Reverse(q.session.date));
+
+        let until = Local::now().naive_local() + chrono::Duration::days(DUE_SOON_DAYS);
+        self.assignments = match read_assignments(self.config) {
+            Ok(assignments) => assignments.sorted().into_iter().filter(|a| a.status == Status::Todo && a.due <= until).cloned().collect(),
+            Err(e) => {
+                self.status = e;
+                Vec::new()
+            }
+        };
+
+        let selected = self.selected.selected().unwrap_or(0).min(self.notes.len().saturating_sub(1));
+        self.selected.select((!self.notes.is_empty()).then_some(selected));
+    }
+
+    fn today(&self, now: Time) -> List<'a> {
+        let current = get_current_classes(self.config, now).into_iter().next();
+
+        let mut meetings = self.config.get_classes()
+            .iter()
+            .flat_map(|class| match class.get_times() {
+                Times::Async => Vec::new(),
+                Times::At(times) => times.iter().filter(|t| t.start.day == now.day).map(|t| (class, t)).collect(),
+            })
+            .collect::<Vec<_>>();
+        meetings.sort_by_key(|(_, t)| t.start.get_hms());
+
+        let items = meetings.into_iter().map(|(class, time)| {
+            let line = format!("{}-{}  {}  {}", time.start.hm_string(), time.end.hm_string(), class.get_name(), place(time));
+            let in_session = current.as_ref().is_some_and(|(c, t)| c.get_name() == class.get_name() && t == time);
+
+            if in_session { ListItem::new(format!("> {line}")).bold() } else { ListItem::new(format!("  {line}")) }
+        });
+
+        List::new(items).block(Block::bordered().title(" Today "))
+    }
+
+    fn now_and_next(&self, now: Time) -> Paragraph<'a> {
+        let mut lines = Vec::new();
+
+        for (class, time) in get_current_classes(self.config, now) {
+            let status = if time.includes(now) {
+                format!("until {} ({} left)", time.end.hm_string(), short_duration(now.seconds_until(&time.end)))
+            } else if now.seconds_since(&time.start) < 0 {
+                format!("starting at {} (in {})", time.start.hm_string(), short_duration(now.seconds_until(&time.start)))
+            } else {
+                format!("ended at {}", time.end.hm_string())
+            };
+
+            lines.push(Line::from(format!("Now: {} {status}, {}", class.get_name(), place(&time))).bold());
+        }
+
+        match next_class(self.config, now) {
+            Some((class, time)) => lines.push(Line::from(format!("Next: {} at {} on {} (in {}), {}",
+                class.get_name(),
+                time.start.hm_string(),
+                time.start.day,
+                short_duration(now.seconds_until(&time.start)),
+                place(&time)
+            ))),
+            None => lines.push(Line::from("No scheduled classes.")),
+        }
+
+        let asynchronous = self.config.get_classes().iter().filter(|c| c.get_times().is_empty()).map(|c| c.get_name().clone()).collect::<Vec<_>>();
+        if !asynchronous.is_empty() {
+            lines.push(Line::from(format!("Asynchronous: {}", asynchronous.join(", "))));
+        }
+
+        Paragraph::new(lines).wrap(Wrap { trim: true }).block(Block::bordered().title(" Now "))
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let now = Time::now();
+
+        let [header, top, bottom, footer] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Percentage(35),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ]).areas(frame.area());
+        let [today, current] = Layout::horizontal([Constraint::Percentage(50); 2]).areas(top);
+        let [notes, side] = Layout::horizontal([Constraint::Percentage(45), Constraint::Fill(1)]).areas(bottom);
+        let [questions, assignments] = Layout::vertical([Constraint::Percentage(50); 2]).areas(side);
+
+        frame.render_widget(Line::from(format!("classnote  {}", Local::now().format("%A %Y-%m-%d %H:%M"))).bold(), header);
+        frame.render_widget(self.today(now), today);
+        frame.render_widget(self.now_and_next(now), current);
+
+        let note_items = self.notes.iter().map(|s| ListItem::new(format!("{}  {}", s.course, s.name())));
+        let note_list = List::new(note_items)
+            .block(Block::bordered().title(" Recent notes "))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(note_list, notes, &mut self.selected);
+
+        let question_items = self.questions.iter().map(|q| ListItem::new(format!("{} ({}): {}", q.session.course, q.session.date, q.text)));
+        frame.render_widget(List::new(question_items).block(Block::bordered().title(" Open questions ")), questions);
+
+        let now_date = Local::now().naive_local();
+        let assignment_items = self.assignments.iter().map(|a| ListItem::new(format!("#{} {}: {}, due {}", a.id, a.course, a.title, describe_due(a.due, now_date))));
+        frame.render_widget(List::new(assignment_items).block(Block::bordered().title(" Assignments ")), assignments);
+
+        let keys = "j/k select  Enter open  n note for class in session  r refresh  q quit";
+        let footer_text = if self.status.is_empty() { keys.to_string() } else { format!("{keys}  | {}", self.status) };
+        frame.render_widget(Line::from(footer_text).dim(), footer);
+    }
+
+    /// Hands the terminal over to `edit` (the editor, or a prompt) and takes it back afterwards
+    fn suspend(&mut self, terminal: &mut DefaultTerminal, edit: impl FnOnce(&Config) -> Result<(), String>) -> io::Result<()> {
+        ratatui::restore();
+        let result = edit(self.config);
+        *terminal = ratatui::init();
+
+        self.refresh();
+        if let Err(e) = result {
+            self.status = e;
+        }
+        return terminal.clear();
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            // redraw every second so the clock and what's in session stay current
+            if !event::poll(Duration::from_secs(1))? {
+                continue;
+            }
+
+            let Event::Key(key) = event::read()? else { continue; };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            self.status.clear();
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Down | KeyCode::Char('j') => self.selected.select_next(),
+                KeyCode::Up | KeyCode::Char('k') => self.selected.select_previous(),
+                KeyCode::Enter => {
+                    if let Some(note) = self.selected.selected().and_then(|i| self.notes.get(i)).map(|s| s.note.clone()) {
+                        self.suspend(terminal, |config| {
+                            edit_note(config, &note, false, false);
+                            return Ok(());
+                        })?;
+                    }
+                },
+                KeyCode::Char('n') => {
+                    if get_current_classes(self.config, Time::now()).is_empty() {
+                        self.status = "No class in session.".into();
+                        continue;
+                    }
+
+                    self.suspend(terminal, |config| {
+                        let (class, _) = get_current_class(config, Time::now())?;
+                        let (path, _, reused) = get_or_create_classnote(config, &class)
+                            .map_err(|e| format!("Unable to create a note for {}: {e:#}", class.get_name()))?;
+
+                        edit_note(config, &path, false, reused);
+                        return Ok(());
+                    })?;
+                },
+                KeyCode::Char('r') => self.refresh(),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Runs the full-screen dashboard until the user quits.
+pub fn run(config: &Config) -> io::Result<()> {
+    let mut dashboard = Dashboard::new(config);
+
+    let mut terminal = ratatui::init();
+    let result = dashboard.run(&mut terminal);
+    ratatui::restore();
+
+    return result;
+}
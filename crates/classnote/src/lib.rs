@@ -0,0 +1,176 @@
+//! Library access to classnote's schedule and notes, for tools that want to ask what class is in session or where a
+//! course's notes are without going through the CLI.
+//!
+//! ```no_run
+//! use classnote::Classnote;
+//!
+//! let classnote = Classnote::load()?;
+//! let (class, meeting) = classnote.current_class()?;
+//! println!("{} until {}", class.get_name(), meeting.end.hms_string());
+//!
+//! let note = classnote.latest_note("MATH-2415")?;
+//! println!("{}", note.display());
+//! # Ok::<(), classnote::Error>(())
+//! ```
+//!
+//! Nothing here prompts or prints to stdout, and every fallible call returns an [`Error`]. Problems that don't stop a
+//! call are reported on stderr the way the CLI reports them, like [`Classnote::create_session`] failing to update the
+//! Obsidian links of the new note.
+//!
+//! # Stability
+//!
+//! [`Classnote`] and [`Error`] are the supported interface. [`Config`], [`Class`], [`ClassTime`], [`Time`] and
+//! [`Session`] are re-exported from classnote's internal crates for convenience, and may change in any release.
+
+use std::{fmt::Display, path::{Path, PathBuf}};
+
+use chrono::NaiveDate;
+use helpers::{config::load_config, note::{get_current_classes, get_latest_classnote, session_note_on}, session::{find_course, get_sessions}};
+
+// unstable, see the crate docs
+pub use data::{Config, class::Class, time::{ClassTime, Time}};
+pub use helpers::session::Session;
+
+/// Why a call failed
+#[derive(Debug)]
+pub enum Error {
+    /// No config has been set up yet. Run `classnote` once to make one.
+    NoConfig,
+    /// The config file couldn't be read or parsed
+    Config(String),
+    /// No class or notes folder has this name
+    UnknownCourse(String),
+    /// No class is in session at the time asked about
+    NoClassInSession(Time),
+    /// The course has no notes yet
+    NoNotes(String),
+    /// Sessions are numbered in the order they're created, so one can't be added before the latest
+    BeforeLatest { course: String, latest: NaiveDate },
+    Io(std::io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NoConfig => f.write_str("No classnote config found."),
+            Error::Config(e) => f.write_str(e),
+            Error::UnknownCourse(course) => write!(f, "No course named \"{course}\" found."),
+            Error::NoClassInSession(time) => write!(f, "No class in session at {time}."),
+            Error::NoNotes(course) => write!(f, "No notes taken for {course} yet."),
+            Error::BeforeLatest { course, latest } => write!(f, "{course} already has a session on {latest}, and sessions can't be added before it."),
+            Error::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// A loaded classnote config, answering questions about its classes and notes
+#[derive(Debug)]
+pub struct Classnote {
+    config: Config,
+}
+
+impl Classnote {
+    /// Wraps an already loaded config.
+    pub fn new(config: Config) -> Classnote {
+        Classnote { config }
+    }
+
+    /// Loads the config the CLI uses, `~/.config/classnote/config.toml`.
+    pub fn load() -> Result<Classnote, Error> {
+        Self::load_config(None)
+    }
+
+    /// Loads a config file from elsewhere.
+    pub fn load_from(path: impl AsRef<Path>) -> Result<Classnote, Error> {
+        Self::load_config(Some(&path.as_ref().to_path_buf()))
+    }
+
+    fn load_config(path: Option<&PathBuf>) -> Result<Classnote, Error> {
+        match load_config(path) {
+            Ok(Some(config)) => Ok(Classnote { config }),
+            Ok(None) => Err(Error::NoConfig),
+            Err(e) => Err(Error::Config(e)),
+        }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Folder notes are kept in
+    pub fn root(&self) -> PathBuf {
+        self.config.get_root()
+    }
+
+    pub fn classes(&self) -> &[Class] {
+        self.config.get_classes()
+    }
+
+    /// The configured class named `name`, ignoring case.
+    pub fn class(&self, name: &str) -> Result<&Class, Error> {
+        self.classes()
+            .iter()
+            .find(|c| c.get_name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| Error::UnknownCourse(name.to_string()))
+    }
+
+    /// The class in session now, with the meeting it's in. See [`Classnote::class_at`].
+    pub fn current_class(&self) -> Result<(Class, ClassTime), Error> {
+        self.class_at(Time::now())
+    }
+
+    /// The class in session at `time`, grace periods included. When several are, the one whose meeting is closest.
+    pub fn class_at(&self, time: Time) -> Result<(Class, ClassTime), Error> {
+        get_current_classes(&self.config, time).into_iter().next().ok_or(Error::NoClassInSession(time))
+    }
+
+    /// Name of the course's folder, from either the config or the notes root.
+    fn course_name(&self, course: &str) -> Result<String, Error> {
+        match self.class(course) {
+            Ok(class) => Ok(class.get_name().clone()),
+            Err(e) => find_course(&self.config, course).ok_or(e),
+        }
+    }
+
+    /// Every session of `course`, oldest first. Courses no longer in the config still have their sessions listed.
+    pub fn sessions(&self, course: &str) -> Result<Vec<Session>, Error> {
+        let course = self.course_name(course)?;
+        Ok(get_sessions(&self.root(), &course))
+    }
+
+    /// Path of the most recent note of `course`.
+    pub fn latest_note(&self, course: &str) -> Result<PathBuf, Error> {
+        let course = self.course_name(course)?;
+        // notes are found by the class's name alone, so a course no longer in the config can stand in as a bare class
+        let class = self.class(&course).cloned().unwrap_or_else(|_| Class::from_names(course.clone(), String::new()));
+
+        get_latest_classnote(&self.config, &class).ok_or(Error::NoNotes(course))
+    }
+
+    /// The session of `course` on `date`, creating its folder and note the way the CLI does if there isn't one yet.
+    /// New sessions go after the latest one, in its week or the next, so `date` can't be before the latest session.
+    pub fn create_session(&self, course: &str, date: NaiveDate) -> Result<Session, Error> {
+        let class = self.class(course)?;
+        if let Some(latest) = get_sessions(&self.root(), class.get_name()).into_iter().map(|s| s.date).max().filter(|latest| date < *latest) {
+            return Err(Error::BeforeLatest { course: class.get_name().clone(), latest });
+        }
+        let (note, _) = session_note_on(&self.config, class, date)?;
+
+        Session::from_note(&self.root(), &note).ok_or_else(|| Error::Io(std::io::Error::other(format!("\"{}\" isn't a session note.", note.display()))))
+    }
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use common::{at, class, config, date};
+use classnote::{Classnote, Error};
+use data::time::Day;
+use tempfile::TempDir;
+
+fn classnote(root: &TempDir) -> Classnote {
+    let math = class("MATH-2415", &[(at(Day::Monday, 9, 0), at(Day::Monday, 10, 0))]);
+    let phys = class("PHYS", &[]);
+
+    Classnote::new(config(root.path(), vec![math, phys]))
+}
+
+#[test]
+fn class_in_session() {
+    let root = TempDir::new().unwrap();
+    let classnote = classnote(&root);
+
+    let (class, meeting) = classnote.class_at(at(Day::Monday, 9, 30)).unwrap();
+    assert_eq!(class.get_name(), "MATH-2415");
+    assert_eq!(meeting.end.hour, 10);
+
+    assert!(matches!(classnote.class_at(at(Day::Tuesday, 9, 30)), Err(Error::NoClassInSession(_))));
+}
+
+#[test]
+fn sessions_and_latest_note() {
+    let root = TempDir::new().unwrap();
+    let classnote = classnote(&root);
+
+    assert!(matches!(classnote.latest_note("math-2415"), Err(Error::NoNotes(course)) if course == "MATH-2415"));
+    assert!(matches!(classnote.sessions("BIO"), Err(Error::UnknownCourse(_))));
+
+    let first = classnote.create_session("MATH-2415", date(14)).unwrap();
+    let second = classnote.create_session("MATH-2415", date(21)).unwrap();
+    assert_eq!((first.week, first.number), (1, 1));
+    assert_eq!(second.week, 2);
+    assert!(second.note.is_file());
+
+    // asking for the same day again finds the session instead of making another
+    assert_eq!(classnote.create_session("MATH-2415", date(21)).unwrap(), second);
+
+    let earlier = classnote.create_session("MATH-2415", date(15));
+    assert!(matches!(earlier, Err(Error::BeforeLatest { latest, .. }) if latest == second.date));
+
+    assert_eq!(classnote.sessions("MATH-2415").unwrap(), [first, second.clone()]);
+    assert_eq!(classnote.latest_note("MATH-2415").unwrap(), second.note);
+}
@@ -0,0 +1,27 @@
+//! Fixtures for the API tests
+
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use classnote::{Class, Config, Time};
+use data::{Location, time::{Day, Times}};
+
+pub fn at(day: Day, hour: u8, minute: u8) -> Time {
+    Time { day, hour, minute, second: 0 }
+}
+
+/// A day of September 2026
+pub fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+}
+
+/// A class taught by Dr. Smith from each start to end, or asynchronous if there are none
+pub fn class(name: &str, times: &[(Time, Time)]) -> Class {
+    let times = times.iter().map(|(start, end)| (*start, *end, Location::default()).into()).collect::<Vec<_>>();
+    Class::new(name.into(), "Dr. Smith".into(), Times::from(times), Times::Async)
+}
+
+/// Config keeping notes under `root`, opened in vim
+pub fn config(root: impl Into<PathBuf>, classes: Vec<Class>) -> Config {
+    Config::new(root.into(), classes, "vim".into())
+}
@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    #[default]
+    Todo,
+    Done,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Assignment {
+    pub id: u32,
+    pub title: String,
+    pub course: String,
+    pub due: NaiveDateTime,
+    #[serde(default)]
+    pub status: Status,
+    /// Note of the session it was assigned in, relative to the notes root
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<PathBuf>,
+}
+
+/// Every assignment across courses
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct Assignments {
+    #[serde(default)]
+    assignments: Vec<Assignment>,
+}
+
+impl Assignments {
+    pub fn get_assignments(&self) -> &Vec<Assignment> {
+        &self.assignments
+    }
+
+    pub fn add(&mut self, title: String, course: String, due: NaiveDateTime, note: Option<PathBuf>) -> &Assignment {
+        let id = self.assignments.iter().map(|a| a.id).max().unwrap_or(0) + 1;
+        self.assignments.push(Assignment { id, title, course, due, status: Status::Todo, note });
+
+        return &self.assignments[self.assignments.len() - 1];
+    }
+
+    pub fn complete(&mut self, id: u32) -> Option<&Assignment> {
+        let assignment = self.assignments.iter_mut().find(|a| a.id == id)?;
+        assignment.status = Status::Done;
+
+        return Some(assignment);
+    }
+
+    /// All assignments, soonest due first
+    pub fn sorted(&self) -> Vec<&Assignment> {
+        let mut sorted = self.assignments.iter().collect::<Vec<_>>();
+        sorted.sort_by_key(|a| (a.due, a.id));
+
+        return sorted;
+    }
+
+    /// Unfinished assignments of `course` due before `until`, overdue ones included, soonest first
+    pub fn due_before(&self, course: &str, until: NaiveDateTime) -> Vec<&Assignment> {
+        self.sorted()
+            .into_iter()
+            .filter(|a| a.status == Status::Todo && a.course.eq_ignore_ascii_case(course) && a.due <= until)
+            .collect()
+    }
+}
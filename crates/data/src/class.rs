@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
-use super::time::Times;
+use super::event::Event;
+use super::time::{Grace, Times};
 
 use serde::{Serialize, Deserialize};
 
@@ -9,7 +10,13 @@ pub struct Class {
     name: String,
     times: Times,
     professor: String,
-    office_hours: Times 
+    office_hours: Times,
+    /// Overrides the config-wide grace period for this class
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    grace: Option<Grace>,
+    /// Exams, deadlines and other dated one-offs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    events: Vec<Event>,
 }
 
 impl Display for Class {
@@ -49,6 +56,8 @@ impl Class {
             times,
             professor,
             office_hours,
+            grace: None,
+            events: Vec::new(),
          }
     }
 
@@ -60,7 +69,53 @@ impl Class {
         &self.professor
     }
 
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    pub fn set_professor(&mut self, professor: String) {
+        self.professor = professor;
+    }
+
     pub fn get_times(&self) -> &Times {
         &self.times
     }
-}
\ No newline at end of file
+
+    pub fn set_times(&mut self, times: Times) {
+        self.times = times;
+    }
+
+    pub fn get_office_hours(&self) -> &Times {
+        &self.office_hours
+    }
+
+    pub fn set_office_hours(&mut self, office_hours: Times) {
+        self.office_hours = office_hours;
+    }
+
+    pub fn get_grace(&self) -> Option<Grace> {
+        self.grace
+    }
+
+    pub fn set_grace(&mut self, grace: Option<Grace>) {
+        self.grace = grace;
+    }
+
+    pub fn get_events(&self) -> &Vec<Event> {
+        &self.events
+    }
+
+    /// Adds an event, keeping events in date order.
+    pub fn add_event(&mut self, event: Event) {
+        self.events.push(event);
+        self.events.sort_by_key(|e| (e.date, e.time));
+    }
+
+    /// Removes every event titled `title` (ignoring case), returning how many there were.
+    pub fn remove_event(&mut self, title: &str) -> usize {
+        let before = self.events.len();
+        self.events.retain(|e| !e.title.eq_ignore_ascii_case(title));
+
+        return before - self.events.len();
+    }
+}
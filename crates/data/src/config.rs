@@ -2,59 +2,35 @@ use std::{io::{Write, stdout}, path::PathBuf};
 
 use serde::{Serialize, Deserialize};
 
-use crate::obsidian::ObsidianPath;
-
+use super::editor::Editor;
 use super::class::Class;
-
-#[derive(Debug, Serialize, Deserialize)]
-pub enum Editor {
-    Simple(String),
-    Complex {
-        program: String,
-        uri: ObsidianPath
-    }
-}
-
-impl Default for Editor {
-    fn default() -> Self {
-        Self::Simple(String::new())
-    }
-}
-
-impl Editor {
-    pub fn is_simple(&self) -> bool {
-        match &self {
-            Editor::Simple(_) => true,
-            _ => false
-        }
-    }
-
-    pub fn get_program(&self) -> &String {
-        match &self {
-            Self::Simple(e) => e,
-            Self::Complex { program, .. } => program
-        }
-    }
-
-    pub fn set_program(&mut self, program: String) {
-        match self {
-            Self::Simple(p) => *p = program,
-            Self::Complex { program: p, .. } => *p = program
-        }
-    }
-}
+use super::time::Grace;
+use super::git::Git;
+use super::recording::Recording;
+use super::notifications::Notifications;
+use super::semester::Semester;
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct Config {
     root: PathBuf,
     classes: Vec<Class>,
     editor: Editor,
+    #[serde(default)]
+    grace: Grace,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    git: Option<Git>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    recording: Option<Recording>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    notifications: Option<Notifications>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    semester: Option<Semester>,
 }
 
 impl Config {
 
     pub fn new(root: PathBuf, classes: Vec<Class>, editor: String) -> Config {
-        Config { root, classes, editor: Editor::Simple(editor) }
+        Config { root, classes, editor: Editor::Simple(editor), ..Default::default() }
     }
     pub fn get_classes(&self) -> &Vec<Class> {
         &self.classes
@@ -77,28 +53,69 @@ impl Config {
     }
 
     pub fn get_editor_program(&self) -> String {
-        let editor = match &self.editor {
-            Editor::Simple(e) => e,
-            Editor::Complex { program: e, .. } => e
-        };
-
-        if !editor.is_empty() {
-            editor.clone()
-        } else {
-            match std::env::var("EDITOR") {
-                Ok(e) => e,
-                Err(_) => {
-                    eprintln!("No editor set, defaulting to vim.");
-                    "vim".into()
-                }
-            }
-        }
+        self.editor.program_name()
+    }
+
+    pub fn get_grace(&self) -> Grace {
+        self.grace
+    }
+
+    pub fn set_grace(&mut self, grace: Grace) {
+        self.grace = grace;
+    }
+
+    /// Grace period for `class`, falling back to the config-wide one
+    pub fn grace_for(&self, class: &Class) -> Grace {
+        class.get_grace().unwrap_or(self.grace)
+    }
+
+    pub fn get_git(&self) -> Option<&Git> {
+        self.git.as_ref()
+    }
+
+    pub fn set_git(&mut self, git: Option<Git>) {
+        self.git = git;
+    }
+
+    pub fn get_recording(&self) -> Option<&Recording> {
+        self.recording.as_ref()
+    }
+
+    pub fn set_recording(&mut self, recording: Option<Recording>) {
+        self.recording = recording;
+    }
+
+    pub fn get_notifications(&self) -> Option<&Notifications> {
+        self.notifications.as_ref()
+    }
+
+    pub fn set_notifications(&mut self, notifications: Option<Notifications>) {
+        self.notifications = notifications;
+    }
+
+    pub fn get_semester(&self) -> Option<&Semester> {
+        self.semester.as_ref()
+    }
+
+    pub fn set_semester(&mut self, semester: Option<Semester>) {
+        self.semester = semester;
     }
 
     pub fn add_class(&mut self, class: Class) {
         self.classes.push(class);
     }
 
+    /// Removes the class named `name` (ignoring case), returning it
+    pub fn remove_class(&mut self, name: &str) -> Option<Class> {
+        let i = self.classes.iter().position(|c| c.get_name().eq_ignore_ascii_case(name))?;
+        Some(self.classes.remove(i))
+    }
+
+    /// The class named `name`, ignoring case
+    pub fn get_class_mut(&mut self, name: &str) -> Option<&mut Class> {
+        self.classes.iter_mut().find(|c| c.get_name().eq_ignore_ascii_case(name))
+    }
+
     pub fn serialize(&self) -> String {
         toml::to_string(self).expect("Encountered error during serialization of TOML data.")
     }
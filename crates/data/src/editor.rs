@@ -0,0 +1,252 @@
+use std::{fmt::Display, path::Path, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::obsidian::{ObsidianPath, encode};
+
+/// Editors classnote knows how to launch out of the box
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Preset {
+    VsCode,
+    Neovim,
+    Helix,
+    Typora,
+    Obsidian,
+}
+
+impl Preset {
+    pub const ALL: [Preset; 5] = [Preset::VsCode, Preset::Neovim, Preset::Helix, Preset::Typora, Preset::Obsidian];
+
+    pub fn template(&self) -> &'static str {
+        match self {
+            Preset::VsCode => "code --wait --goto {path}:{line}",
+            Preset::Neovim => "nvim +{line} {path}",
+            Preset::Helix => "hx {path}:{line}",
+            Preset::Typora => "typora {path}",
+            Preset::Obsidian => "obsidian {uri}",
+        }
+    }
+
+    pub fn waits(&self) -> bool {
+        !matches!(self, Preset::Typora | Preset::Obsidian)
+    }
+}
+
+impl FromStr for Preset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "vscode" | "code"  => Ok(Preset::VsCode),
+            "neovim" | "nvim"  => Ok(Preset::Neovim),
+            "helix"  | "hx"    => Ok(Preset::Helix),
+            "typora"           => Ok(Preset::Typora),
+            "obsidian"         => Ok(Preset::Obsidian),
+            _ => Err(format!("Unknown editor preset \"{s}\"."))
+        }
+    }
+}
+
+impl Display for Preset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Preset::VsCode => "vscode",
+            Preset::Neovim => "neovim",
+            Preset::Helix => "helix",
+            Preset::Typora => "typora",
+            Preset::Obsidian => "obsidian",
+        })
+    }
+}
+
+/// Splits `template` into arguments and fills in `{path}`, `{line}` and `{uri}`, appending the path if the template never mentions it.
+/// Placeholders are substituted after splitting, so paths with spaces stay a single argument.
+pub fn expand_template(template: &str, path: &Path, line: Option<usize>, uri: &str) -> Vec<String> {
+    let path = path.to_string_lossy();
+    let line = line.unwrap_or(1).to_string();
+
+    let mut args = template
+        .split_whitespace()
+        .map(|arg| arg.replace("{path}", &path).replace("{line}", &line).replace("{uri}", uri))
+        .collect::<Vec<_>>();
+
+    if !template.contains("{path}") && !template.contains("{uri}") {
+        args.push(path.to_string());
+    }
+
+    return args;
+}
+
+// the configured program, falling back to $EDITOR and then vim
+fn program_or_default(program: &str) -> String {
+    if !program.is_empty() {
+        return program.to_string();
+    }
+
+    match std::env::var("EDITOR") {
+        Ok(e) if !e.is_empty() => e,
+        _ => {
+            eprintln!("No editor set, defaulting to vim.");
+            "vim".into()
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum Editor {
+    Simple(String),
+    Complex {
+        program: String,
+        uri: ObsidianPath
+    },
+    /// An argument template like `nvim +{line} {path}`, or the name of a [`Preset`]
+    Custom {
+        command: String,
+        /// Overrides whether the editor blocks until the note is closed
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        wait: Option<bool>,
+    },
+}
+
+impl Default for Editor {
+    fn default() -> Self {
+        Self::Simple(String::new())
+    }
+}
+
+impl Editor {
+    pub fn is_simple(&self) -> bool {
+        matches!(self, Editor::Simple(_))
+    }
+
+    pub fn get_program(&self) -> &String {
+        match &self {
+            Self::Simple(e) => e,
+            Self::Complex { program, .. } => program,
+            Self::Custom { command, .. } => command,
+        }
+    }
+
+    pub fn set_program(&mut self, program: String) {
+        match self {
+            Self::Simple(p) => *p = program,
+            Self::Complex { program: p, .. } => *p = program,
+            Self::Custom { command, .. } => *command = program,
+        }
+    }
+
+    /// The Obsidian vault notes are opened in, if any
+    pub fn obsidian(&self) -> Option<&ObsidianPath> {
+        match self {
+            Self::Complex { uri, .. } => Some(uri),
+            _ => None,
+        }
+    }
+
+    /// The preset a `Custom` editor refers to by name
+    pub fn preset(&self) -> Option<Preset> {
+        match self {
+            Self::Custom { command, .. } => command.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Name of the program that gets launched
+    pub fn program_name(&self) -> String {
+        match self {
+            Self::Custom { command, .. } if !command.trim().is_empty() => self.preset().map_or(command.as_str(), |p| p.template())
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            Self::Custom { .. } => program_or_default(""),
+            _ => program_or_default(self.get_program()),
+        }
+    }
+
+    /// The backend that launches this editor
+    pub fn backend(&self) -> Box<dyn EditorBackend + '_> {
+        match self {
+            Self::Simple(program) => Box::new(Program(program)),
+            Self::Complex { program, uri } => Box::new(ObsidianVault { program, uri }),
+            // the settings form can save an empty command, which is as good as none
+            Self::Custom { command, .. } if command.trim().is_empty() => Box::new(Program("")),
+            Self::Custom { command, wait } => {
+                let preset = self.preset();
+                Box::new(Template {
+                    template: preset.map_or(command.as_str(), |p| p.template()),
+                    wait: wait.unwrap_or(preset.is_none_or(|p| p.waits())),
+                })
+            }
+        }
+    }
+}
+
+/// How notes get opened in an editor
+pub trait EditorBackend {
+    /// Program and arguments that open `path`, at `line` if the editor supports it
+    fn command(&self, path: &Path, line: Option<usize>) -> Vec<String>;
+
+    /// Whether the program keeps running until the note is closed. GUI editors often hand the file off and return immediately.
+    fn waits(&self) -> bool;
+}
+
+/// A program given the note's path, falling back to `$EDITOR` if it's empty. Editors known to take a line to open at
+/// are given that too.
+pub struct Program<'a>(pub &'a str);
+
+impl EditorBackend for Program<'_> {
+    fn command(&self, path: &Path, line: Option<usize>) -> Vec<String> {
+        let program = program_or_default(self.0);
+        let path = path.to_string_lossy().to_string();
+        let Some(line) = line else { return vec![program, path]; };
+
+        // recognized by the executable's name, so full paths like /usr/bin/nvim work too
+        let name = Path::new(&program).file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        match name.as_str() {
+            "vi" | "vim" | "nvim" | "nano" | "emacs" | "emacsclient" | "micro" | "kak" => vec![program, format!("+{line}"), path],
+            "hx" | "helix" => vec![program, format!("{path}:{line}")],
+            "code" | "codium" => vec![program, "--goto".into(), format!("{path}:{line}")],
+            _ => vec![program, path],
+        }
+    }
+
+    fn waits(&self) -> bool {
+        true
+    }
+}
+
+/// Obsidian, opening notes by their path in a known vault
+pub struct ObsidianVault<'a> {
+    pub program: &'a str,
+    pub uri: &'a ObsidianPath,
+}
+
+impl EditorBackend for ObsidianVault<'_> {
+    fn command(&self, path: &Path, _line: Option<usize>) -> Vec<String> {
+        vec![self.program.to_string(), self.uri.build_uri(self.uri.resolve_vault_path().as_deref(), path)]
+    }
+
+    // Obsidian hands the URI to the running app and exits
+    fn waits(&self) -> bool {
+        false
+    }
+}
+
+/// An argument template like `nvim +{line} {path}`, from the config or a [`Preset`]
+pub struct Template<'a> {
+    pub template: &'a str,
+    pub wait: bool,
+}
+
+impl EditorBackend for Template<'_> {
+    fn command(&self, path: &Path, line: Option<usize>) -> Vec<String> {
+        // without a vault to open it in, let Obsidian find the vault containing the note
+        let uri = format!("obsidian://open?path={}", encode(&path.to_string_lossy()));
+        expand_template(self.template, path, line, &uri)
+    }
+
+    fn waits(&self) -> bool {
+        self.wait
+    }
+}
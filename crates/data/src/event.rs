@@ -0,0 +1,44 @@
+use std::fmt::Display;
+
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+/// A one-off date in a course, like a midterm or a drop deadline
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Event {
+    pub title: String,
+    pub date: NaiveDate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<NaiveTime>,
+}
+
+impl Event {
+    pub fn days_until(&self, today: NaiveDate) -> i64 {
+        (self.date - today).num_days()
+    }
+
+    /// e.g. "in 9 days", "tomorrow", "3 days ago"
+    pub fn relative(&self, today: NaiveDate) -> String {
+        match self.days_until(today) {
+            0 => "today".into(),
+            1 => "tomorrow".into(),
+            -1 => "yesterday".into(),
+            days if days < 0 => format!("{} days ago", -days),
+            days => format!("in {days} days"),
+        }
+    }
+
+    /// e.g. "Midterm in 9 days", "Final tomorrow"
+    pub fn countdown(&self, today: NaiveDate) -> String {
+        format!("{} {}", self.title, self.relative(today))
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.time {
+            Some(time) => write!(f, "{} on {} at {}", self.title, self.date.format("%a %Y-%m-%d"), time.format("%H:%M")),
+            None => write!(f, "{} on {}", self.title, self.date.format("%a %Y-%m-%d")),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Serialize, Deserialize};
+
+/// Opt-in version control of the notes root. Present in the config only when enabled.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Git {
+    /// URL or path of a remote to sync with, added as `origin`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+    #[serde(default = "default_branch")]
+    pub branch: String,
+    /// Pull from the remote after committing
+    #[serde(default)]
+    pub pull: bool,
+    /// Push to the remote after committing (and pulling, if enabled)
+    #[serde(default)]
+    pub push: bool,
+}
+
+fn default_branch() -> String {
+    "main".into()
+}
+
+impl Default for Git {
+    fn default() -> Self {
+        Git { remote: None, branch: default_branch(), pull: false, push: false }
+    }
+}
@@ -1,9 +1,17 @@
+pub mod assignment;
 pub mod class;
 pub mod time;
 pub mod obsidian;
+pub mod git;
+pub mod recording;
+pub mod editor;
+pub mod event;
+pub mod notifications;
+pub mod semester;
+pub use editor::Editor;
 
 mod location;
 pub use location::Location;
 
 mod config;
-pub use config::{ Config, Editor };
\ No newline at end of file
+pub use config::Config;
\ No newline at end of file
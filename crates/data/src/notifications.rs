@@ -0,0 +1,26 @@
+use serde::{Serialize, Deserialize};
+
+/// Reminders sent by `classnote daemon`. Present in the config only when changed from the defaults.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct Notifications {
+    /// Minutes before a meeting starts to send its reminder
+    #[serde(default = "default_before")]
+    pub before: u32,
+    /// Also send reminders for office hours
+    #[serde(default = "default_office_hours")]
+    pub office_hours: bool,
+}
+
+fn default_before() -> u32 {
+    10
+}
+
+fn default_office_hours() -> bool {
+    true
+}
+
+impl Default for Notifications {
+    fn default() -> Self {
+        Notifications { before: default_before(), office_hours: default_office_hours() }
+    }
+}
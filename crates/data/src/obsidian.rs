@@ -1,46 +1,120 @@
-use std::{path::PathBuf, str::FromStr};
+use std::{collections::HashMap, path::{Path, PathBuf}};
 
 use serde::{Deserialize, Serialize};
 
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct ObsidianPath {
     pub vault: String,
-    pub courses_root: PathBuf
+    pub courses_root: PathBuf,
+    /// Where the vault lives on disk, as listed in Obsidian's `obsidian.json`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vault_path: Option<PathBuf>,
+}
+
+/// A vault known to Obsidian
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vault {
+    pub id: String,
+    pub path: PathBuf,
+}
+
+impl Vault {
+    /// Obsidian names vaults after their folder
+    pub fn name(&self) -> String {
+        self.path.file_name().unwrap_or_default().to_string_lossy().to_string()
+    }
+
+    pub fn contains(&self, path: &Path) -> bool {
+        path.starts_with(&self.path)
+    }
+}
+
+#[derive(Deserialize)]
+struct VaultEntry {
+    path: PathBuf,
+}
+
+#[derive(Deserialize)]
+struct ObsidianConfig {
+    #[serde(default)]
+    vaults: HashMap<String, VaultEntry>,
+}
+
+/// Reads the vaults out of the contents of an `obsidian.json`, sorted by path.
+pub fn parse_vaults(json: &str) -> Result<Vec<Vault>, String> {
+    let config: ObsidianConfig = serde_json::from_str(json).map_err(|e| format!("Unable to parse Obsidian's config: {e}"))?;
+
+    let mut vaults = config.vaults
+        .into_iter()
+        .map(|(id, entry)| Vault { id, path: entry.path })
+        .collect::<Vec<_>>();
+    vaults.sort_by(|a, b| a.path.cmp(&b.path));
+
+    return Ok(vaults);
+}
+
+/// Location of Obsidian's `obsidian.json` on this platform
+pub fn obsidian_config_path() -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        return std::env::var_os("APPDATA").map(|appdata| PathBuf::from(appdata).join("obsidian/obsidian.json"));
+    }
+
+    let home = std::env::home_dir()?;
+    if cfg!(target_os = "macos") {
+        return Some(home.join("Library/Application Support/obsidian/obsidian.json"));
+    }
+
+    let config = std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from).unwrap_or_else(|| home.join(".config"));
+    return Some(config.join("obsidian/obsidian.json"));
+}
+
+/// Every vault Obsidian knows about. Empty if Obsidian isn't installed or has never opened a vault.
+pub fn discover_vaults() -> Vec<Vault> {
+    obsidian_config_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| parse_vaults(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Percent-encodes everything but the characters RFC 3986 leaves unreserved.
+pub fn encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Path of `note` relative to the vault, with `/` separators as Obsidian expects.
+pub fn file_in_vault(vault_path: &Path, note: &Path) -> Option<String> {
+    let relative = note.strip_prefix(vault_path).ok()?;
+
+    return Some(
+        relative
+            .iter()
+            .map(|component| component.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    );
 }
 
 impl ObsidianPath {
-    pub fn to_uri(&self) -> PathBuf {
-        PathBuf::from_str(
-            &format!(
-                "obsidian://open?vault={}&file={}",
-                self.vault
-                    .replace(' ', "%20"),
-                self.courses_root
-                    .to_string_lossy()
-                    .replace(' ', "%20")
-                    .replace('/', "%2F")
-                // "obsidian://open?path={}"
-            )
-        ).expect("Unable to build Obsidian URI.")
+    /// The vault's path from the config, or from Obsidian's vault list for configs written before it was stored.
+    pub fn resolve_vault_path(&self) -> Option<PathBuf> {
+        if self.vault_path.is_some() {
+            return self.vault_path.clone();
+        }
+
+        discover_vaults().into_iter().find(|v| v.name() == self.vault).map(|v| v.path)
     }
 
-    pub fn build_uri(vault: &String, note_path: PathBuf) -> String {
-        format!("obsidian://open?vault={}&file={}",
-            vault
-                .replace(' ', "%20")
-                .replace('/', "%20")
-            ,
-            // ? There has to be a better way
-            note_path
-                .iter()
-                .skip_while(|&d| d != PathBuf::from(vault)) // trim path until the vault name
-                .skip(1) // also trim the vault name
-                .filter_map(|osstr| osstr.to_str().and_then(|s| Some(s.to_string()))) // better way?
-                .collect::<Vec<String>>()
-                .join("%2F")
-                .replace(' ', "%20")
-                .replace('/', "%2F")
-        )
+    /// URI that opens `note_path` in Obsidian. Without a known vault path, Obsidian is asked to find the vault containing the note itself.
+    pub fn build_uri(&self, vault_path: Option<&Path>, note_path: &Path) -> String {
+        match vault_path.and_then(|vault_path| file_in_vault(vault_path, note_path)) {
+            Some(file) => format!("obsidian://open?vault={}&file={}", encode(&self.vault), encode(&file)),
+            None => format!("obsidian://open?path={}", encode(&note_path.to_string_lossy())),
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,26 @@
+use serde::{Serialize, Deserialize};
+
+/// How lectures are recorded alongside notes. Present in the config only when enabled.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Recording {
+    /// Recorder command run until the editor exits, with `{path}` replaced by the output file, e.g. `arecord -f cd {path}`.
+    /// When unset, the built-in recorder is used if classnote was built with the `audio` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// Extension of the recordings the command produces
+    #[serde(default = "default_extension")]
+    pub extension: String,
+    /// Record whenever a class in session is opened, without passing `--record`
+    #[serde(default)]
+    pub always: bool,
+}
+
+fn default_extension() -> String {
+    "wav".into()
+}
+
+impl Default for Recording {
+    fn default() -> Self {
+        Recording { command: None, extension: default_extension(), always: false }
+    }
+}
@@ -0,0 +1,35 @@
+use chrono::NaiveDate;
+use serde::{Serialize, Deserialize};
+
+/// Dates classes meet between, used to tell which sessions were missed
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Semester {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub holidays: Vec<Holiday>,
+}
+
+/// A day, or a break of several days, without classes
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Holiday {
+    pub date: NaiveDate,
+    /// Last day of a break, if it's longer than `date`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+}
+
+impl Holiday {
+    pub fn includes(&self, date: NaiveDate) -> bool {
+        date >= self.date && date <= self.until.unwrap_or(self.date)
+    }
+}
+
+impl Semester {
+    /// Whether classes meet on `date`: within the semester and not on a holiday
+    pub fn in_session(&self, date: NaiveDate) -> bool {
+        date >= self.start && date <= self.end && !self.holidays.iter().any(|h| h.includes(date))
+    }
+}
@@ -7,7 +7,7 @@ use crate::Location;
 // use chrono // figure out usage
 
 // 1-indexed to match unix `date +%u` output
-#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Eq, Hash, Clone, Copy)]
 #[repr(u8)]
 pub enum Day {
     #[default]
@@ -22,6 +22,9 @@ pub enum Day {
 }
 
 impl Day {
+    /// Every meeting day, Monday first
+    pub const WEEK: [Day; 7] = [Day::Monday, Day::Tuesday, Day::Wednesday, Day::Thursday, Day::Friday, Day::Saturday, Day::Sunday];
+
     pub fn tomorrow(self) -> Day {
         match self {
             Day::Async => Day::Async,
@@ -36,6 +39,20 @@ impl Day {
     }
 }
 
+impl From<Weekday> for Day {
+    fn from(weekday: Weekday) -> Self {
+        match weekday {
+            Weekday::Mon => Day::Monday,
+            Weekday::Tue => Day::Tuesday,
+            Weekday::Wed => Day::Wednesday,
+            Weekday::Thu => Day::Thursday,
+            Weekday::Fri => Day::Friday,
+            Weekday::Sat => Day::Saturday,
+            Weekday::Sun => Day::Sunday,
+        }
+    }
+}
+
 impl Display for Day {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(
@@ -67,7 +84,7 @@ impl FromStr for Day {
             _ if "wednesday".starts_with(d) => Ok(Day::Wednesday),
             _ if "thursday".starts_with(d)  => Ok(Day::Thursday),
             _ if "friday".starts_with(d)    => Ok(Day::Friday),
-            _ if d.trim().len() == 0        => Ok(Day::Async),
+            _ if d.trim().is_empty()        => Ok(Day::Async),
             _ => Err(format!("Unable to parse date from input: \"{s}\""))
         }
     }
@@ -85,25 +102,17 @@ impl Time {
     pub fn now() -> Self {
         let now = Local::now();
 
-        let day = match now.weekday() {
-            Weekday::Mon => Day::Monday,
-            Weekday::Tue => Day::Tuesday,
-            Weekday::Wed => Day::Wednesday,
-            Weekday::Thu => Day::Thursday,
-            Weekday::Fri => Day::Friday,
-            Weekday::Sat => Day::Saturday,
-            Weekday::Sun => Day::Sunday,
-        };
-
         Time {
-            day,
+            day: now.weekday().into(),
             hour: now.hour() as u8,
             minute: now.minute() as u8,
             second: now.second() as u8,
         }
     }
 
-    fn to_absolute_seconds(&self) -> u32 {
+    const WEEK_SECONDS: i64 = 60 * 60 * 24 * 7;
+
+    fn to_absolute_seconds(self) -> u32 {
         // guard in caller for Async, otherwise it will be treated as Monday
         let day = (self.day as u32).saturating_sub(1); // - 1 to account for 1-indexing in enum
         let hour = self.hour as u32;
@@ -113,6 +122,44 @@ impl Time {
         (60 * 60 * 24 * day) + (60 * 60 * hour) + (60 * minute) + second
     }
 
+    fn from_absolute_seconds(secs: u32) -> Time {
+        let day = match secs / (60 * 60 * 24) {
+            0 => Day::Monday,
+            1 => Day::Tuesday,
+            2 => Day::Wednesday,
+            3 => Day::Thursday,
+            4 => Day::Friday,
+            5 => Day::Saturday,
+            _ => Day::Sunday,
+        };
+
+        Time {
+            day,
+            hour: (secs / (60 * 60) % 24) as u8,
+            minute: (secs / 60 % 60) as u8,
+            second: (secs % 60) as u8,
+        }
+    }
+
+    /// Shifts the time by `seconds`, wrapping around the week. Async times are returned unchanged.
+    pub fn offset(self, seconds: i64) -> Time {
+        if self.day == Day::Async { return self; }
+
+        let shifted = (self.to_absolute_seconds() as i64 + seconds).rem_euclid(Self::WEEK_SECONDS);
+        Time::from_absolute_seconds(shifted as u32)
+    }
+
+    /// Seconds from `self` forward to `other`, wrapping around the week.
+    pub fn seconds_until(&self, other: &Time) -> u32 {
+        (other.to_absolute_seconds() as i64 - self.to_absolute_seconds() as i64).rem_euclid(Self::WEEK_SECONDS) as u32
+    }
+
+    /// Signed seconds from `earlier` to `self`, going whichever way around the week is shorter.
+    pub fn seconds_since(&self, earlier: &Time) -> i64 {
+        let forward = earlier.seconds_until(self) as i64;
+        if forward > Self::WEEK_SECONDS / 2 { forward - Self::WEEK_SECONDS } else { forward }
+    }
+
     fn is_between(&self, start: &Time, end: &Time) -> bool {
         if self.day == Day::Async || start.day == Day::Async || end.day == Day::Async {
             return true;
@@ -134,6 +181,11 @@ impl Time {
     pub fn hms_string(&self) -> String {
         format!("{:0>2}:{:0>2}:{:0>2}", self.hour, self.minute, self.second)
     }
+
+    /// Like [`Time::hms_string`] without the seconds, e.g. "09:05"
+    pub fn hm_string(&self) -> String {
+        format!("{:0>2}:{:0>2}", self.hour, self.minute)
+    }
     
     pub fn get_hms(&self) -> (u8, u8, u8) {
         (self.hour, self.minute, self.second)
@@ -170,6 +222,10 @@ impl Times {
             Self::At(times) => times.len()
         }
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
@@ -189,6 +245,15 @@ impl From<(Time, Time, Location)> for ClassTime {
     }
 }
 
+/// Minutes before a class starts and after it ends during which it still counts as in session.
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone, Copy)]
+pub struct Grace {
+    #[serde(default)]
+    pub early: u16,
+    #[serde(default)]
+    pub late: u16,
+}
+
 impl ClassTime {
     pub fn includes(&self, time: Time) -> bool {
         // let (start_hours, start_mins, start_secs) = self.start.get_hms();
@@ -199,4 +264,18 @@ impl ClassTime {
         
         time.is_between(&self.start, &self.end)
     }
-}
\ No newline at end of file
+
+    pub fn includes_with_grace(&self, time: Time, grace: Grace) -> bool {
+        let start = self.start.offset(-60 * grace.early as i64);
+        let end = self.end.offset(60 * grace.late as i64);
+
+        time.is_between(&start, &end)
+    }
+
+    /// Seconds between `time` and the nearest edge of the meeting, or 0 if `time` falls within it.
+    pub fn distance(&self, time: Time) -> u32 {
+        if self.includes(time) { return 0; }
+
+        time.seconds_until(&self.start).min(self.end.seconds_until(&time))
+    }
+}
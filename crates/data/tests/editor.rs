@@ -0,0 +1,70 @@
+use std::path::Path;
+
+use data::{Config, Editor, editor::{Preset, expand_template}};
+
+const NOTE: &str = "/notes/CS 101/Week-1/Class-1-2025-09-01/Class-1-2025-09-01.md";
+
+#[test]
+fn template_keeps_paths_with_spaces_as_one_argument() {
+    assert_eq!(
+        expand_template("code --goto {path}:{line}", Path::new(NOTE), Some(12), ""),
+        vec!["code", "--goto", &format!("{NOTE}:12")]
+    );
+    assert_eq!(expand_template("nvim +{line} {path}", Path::new(NOTE), None, ""), vec!["nvim", "+1", NOTE]);
+}
+
+#[test]
+fn template_without_path_gets_it_appended() {
+    assert_eq!(expand_template("emacsclient -c", Path::new(NOTE), Some(3), ""), vec!["emacsclient", "-c", NOTE]);
+}
+
+#[test]
+fn presets_are_found_by_name() {
+    let editor = Editor::Custom { command: "hx".into(), wait: None };
+    assert_eq!(editor.preset(), Some(Preset::Helix));
+    assert_eq!(editor.backend().command(Path::new(NOTE), Some(4)), vec!["hx", &format!("{NOTE}:4")]);
+    assert!(editor.backend().waits());
+
+    let typora = Editor::Custom { command: "typora".into(), wait: None };
+    assert!(!typora.backend().waits());
+    assert!(Editor::Custom { command: "typora".into(), wait: Some(true) }.backend().waits());
+}
+
+#[test]
+fn obsidian_preset_opens_by_path() {
+    let editor = Editor::Custom { command: "obsidian".into(), wait: None };
+    assert_eq!(
+        editor.backend().command(Path::new("/v/a b.md"), None),
+        vec!["obsidian", "obsidian://open?path=%2Fv%2Fa%20b.md"]
+    );
+}
+
+#[test]
+fn existing_editor_configs_still_parse() {
+    let simple: Config = toml::from_str("root = \"/notes\"\nclasses = []\n[editor]\nSimple = \"vim\"\n").unwrap();
+    assert_eq!(simple.get_editor().backend().command(Path::new(NOTE), Some(5)), vec!["vim", "+5", NOTE]);
+
+    let custom: Config = toml::from_str("root = \"/notes\"\nclasses = []\n[editor.Custom]\ncommand = \"nvim +{line} {path}\"\n").unwrap();
+    assert_eq!(custom.get_editor().backend().command(Path::new(NOTE), Some(5)), vec!["nvim", "+5", NOTE]);
+    assert_eq!(custom.get_editor_program(), "nvim");
+}
+
+#[test]
+fn simple_editors_open_at_the_line_they_support() {
+    let command = |program: &str| Editor::Simple(program.into()).backend().command(Path::new(NOTE), Some(7));
+
+    assert_eq!(command("/usr/bin/nvim"), vec!["/usr/bin/nvim", "+7", NOTE]);
+    assert_eq!(command("hx"), vec!["hx", &format!("{NOTE}:7")]);
+    assert_eq!(command("code"), vec!["code", "--goto", &format!("{NOTE}:7")]);
+    assert_eq!(command("gedit"), vec!["gedit", NOTE]);
+    assert_eq!(Editor::Simple("vim".into()).backend().command(Path::new(NOTE), None), vec!["vim", NOTE]);
+}
+
+#[test]
+fn empty_command_falls_back_to_the_default_editor() {
+    let args = Editor::Custom { command: " ".into(), wait: None }.backend().command(Path::new(NOTE), None);
+
+    assert_eq!(args.len(), 2);
+    assert_ne!(args[0], NOTE);
+    assert_eq!(args[1], NOTE);
+}
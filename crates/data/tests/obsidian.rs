@@ -0,0 +1,63 @@
+use std::path::{Path, PathBuf};
+
+use data::obsidian::{ObsidianPath, Vault, encode, file_in_vault, parse_vaults};
+
+fn vault(name: &str, path: &str) -> ObsidianPath {
+    ObsidianPath { vault: name.into(), courses_root: PathBuf::from(path), vault_path: Some(PathBuf::from(path)) }
+}
+
+#[test]
+fn reads_vaults_from_obsidian_json() {
+    let json = r#"{
+        "vaults": {
+            "b2c3": { "path": "/home/me/Documents/School", "ts": 1700000000000, "open": true },
+            "a1b2": { "path": "/home/me/Personal", "ts": 1690000000000 }
+        },
+        "frame": "hidden"
+    }"#;
+
+    let vaults = parse_vaults(json).unwrap();
+    assert_eq!(vaults, vec![
+        Vault { id: "b2c3".into(), path: "/home/me/Documents/School".into() },
+        Vault { id: "a1b2".into(), path: "/home/me/Personal".into() },
+    ]);
+    assert_eq!(vaults[0].name(), "School");
+}
+
+#[test]
+fn encodes_reserved_and_non_ascii_characters() {
+    assert_eq!(encode("Notes #1 & Q?"), "Notes%20%231%20%26%20Q%3F");
+    assert_eq!(encode("Café/Ü"), "Caf%C3%A9%2F%C3%9C");
+    assert_eq!(encode("a-b_c.d~e"), "a-b_c.d~e");
+}
+
+#[test]
+fn file_is_relative_to_nested_vault() {
+    let note = Path::new("/home/me/Documents/School/Courses/Fall 2025/CS 101/Week-1/Class-1-2025-09-01/Class-1-2025-09-01.md");
+    assert_eq!(
+        file_in_vault(Path::new("/home/me/Documents/School"), note).as_deref(),
+        Some("Courses/Fall 2025/CS 101/Week-1/Class-1-2025-09-01/Class-1-2025-09-01.md")
+    );
+    assert_eq!(file_in_vault(Path::new("/home/me/Personal"), note), None);
+}
+
+#[test]
+fn uri_uses_the_vault_path_not_a_matching_folder_name() {
+    // the vault's name also appears deeper in the path, which must not cut the file path short
+    let obsidian = vault("Notes", "/srv/Notes");
+    let note = Path::new("/srv/Notes/Notes/C# & Más?/Week-1/Class-1-2025-09-01/Class-1-2025-09-01.md");
+
+    assert_eq!(
+        obsidian.build_uri(obsidian.vault_path.as_deref(), note),
+        "obsidian://open?vault=Notes&file=Notes%2FC%23%20%26%20M%C3%A1s%3F%2FWeek-1%2FClass-1-2025-09-01%2FClass-1-2025-09-01.md"
+    );
+}
+
+#[test]
+fn uri_falls_back_to_absolute_path_outside_the_vault() {
+    let obsidian = vault("My Vault", "/home/me/My Vault");
+    let note = Path::new("/tmp/notes/a b.md");
+
+    assert_eq!(obsidian.build_uri(obsidian.vault_path.as_deref(), note), "obsidian://open?path=%2Ftmp%2Fnotes%2Fa%20b.md");
+    assert_eq!(obsidian.build_uri(None, note), "obsidian://open?path=%2Ftmp%2Fnotes%2Fa%20b.md");
+}
@@ -0,0 +1,49 @@
+use data::{Config, Location, time::{ClassTime, Day, Grace, Time}};
+
+fn at(day: Day, hour: u8, minute: u8) -> Time {
+    Time { day, hour, minute, second: 0 }
+}
+
+#[test]
+fn offsets_wrap_around_the_week() {
+    assert_eq!(at(Day::Sunday, 23, 50).offset(20 * 60), at(Day::Monday, 0, 10));
+    assert_eq!(at(Day::Monday, 0, 10).offset(-20 * 60), at(Day::Sunday, 23, 50));
+    assert_eq!(at(Day::Wednesday, 9, 0).offset(7 * 24 * 60 * 60), at(Day::Wednesday, 9, 0));
+    assert_eq!(at(Day::Async, 9, 0).offset(60 * 60), at(Day::Async, 9, 0));
+}
+
+#[test]
+fn seconds_between_times_across_sunday() {
+    let sunday = at(Day::Sunday, 23, 0);
+    let monday = at(Day::Monday, 1, 0);
+
+    assert_eq!(sunday.seconds_until(&monday), 2 * 60 * 60);
+    assert_eq!(monday.seconds_until(&sunday), (7 * 24 - 2) * 60 * 60);
+    assert_eq!(monday.seconds_since(&sunday), 2 * 60 * 60);
+    assert_eq!(sunday.seconds_since(&monday), -2 * 60 * 60);
+    assert_eq!(monday.seconds_until(&monday), 0);
+}
+
+#[test]
+fn grace_extends_meetings_over_midnight() {
+    let meeting = ClassTime::from((at(Day::Sunday, 22, 0), at(Day::Sunday, 23, 50), Location::default()));
+    let grace = Grace { early: 10, late: 15 };
+
+    assert!(!meeting.includes(at(Day::Monday, 0, 0)));
+    assert!(meeting.includes_with_grace(at(Day::Monday, 0, 5), grace));
+    assert!(meeting.includes_with_grace(at(Day::Sunday, 21, 50), grace));
+    assert!(!meeting.includes_with_grace(at(Day::Monday, 0, 6), grace));
+
+    // asynchronous meetings are always in session
+    let anytime = ClassTime::from((at(Day::Async, 0, 0), at(Day::Async, 0, 0), Location::default()));
+    assert!(anytime.includes_with_grace(at(Day::Tuesday, 12, 0), grace));
+}
+
+#[test]
+fn grace_can_leave_out_either_side() {
+    let config: Config = toml::from_str("root = \"/notes\"\nclasses = []\ngrace = { early = 5 }\n[editor]\nSimple = \"vim\"\n").unwrap();
+    assert_eq!(config.get_grace(), Grace { early: 5, late: 0 });
+
+    let config: Config = toml::from_str("root = \"/notes\"\nclasses = []\n[grace]\nlate = 10\n[editor]\nSimple = \"vim\"\n").unwrap();
+    assert_eq!(config.get_grace(), Grace { early: 0, late: 10 });
+}
@@ -0,0 +1,102 @@
+use std::{fs::File, io::Write, path::Path};
+
+use chrono::Utc;
+use zip::{CompressionMethod, ZipWriter, write::SimpleFileOptions};
+
+use super::html::{escape, render_page, table_of_contents};
+use super::pack::{Page, StudyPack, Week, mime_type};
+
+const CONTAINER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles>
+    <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+  </rootfiles>
+</container>
+"#;
+
+fn xhtml(title: &str, body: &str) -> String {
+    format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE html>\n<html xmlns=\"http://www.w3.org/1999/xhtml\" xmlns:epub=\"http://www.idpf.org/2007/ops\">\n<head>\n<meta charset=\"utf-8\" />\n<title>{}</title>\n</head>\n<body>\n{body}</body>\n</html>\n", escape(title))
+}
+
+fn chapter_file(week: &Week) -> String {
+    format!("{}.xhtml", week.anchor())
+}
+
+// images from different sessions may share a file name, so prefix them with the session
+fn image_file(page: &Page, image: &Path) -> String {
+    format!("images/{}-{}", page.anchor(), image.file_name().unwrap_or_default().to_string_lossy().replace(' ', "_"))
+}
+
+fn package(pack: &StudyPack) -> String {
+    let mut manifest = String::from("    <item id=\"nav\" href=\"nav.xhtml\" media-type=\"application/xhtml+xml\" properties=\"nav\"/>\n");
+    let mut spine = String::from("    <itemref idref=\"nav\"/>\n");
+
+    for week in &pack.weeks {
+        manifest += &format!("    <item id=\"{}\" href=\"{}\" media-type=\"application/xhtml+xml\"/>\n", week.anchor(), chapter_file(week));
+        spine += &format!("    <itemref idref=\"{}\"/>\n", week.anchor());
+
+        for (i, (page, image)) in week.pages.iter().flat_map(|p| p.images.iter().map(move |i| (p, i))).enumerate() {
+            manifest += &format!("    <item id=\"{}-image-{i}\" href=\"{}\" media-type=\"{}\"/>\n", week.anchor(), escape(&image_file(page, image)), mime_type(image));
+        }
+    }
+
+    format!(r#"<?xml version="1.0" encoding="UTF-8"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="id">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:identifier id="id">urn:classnote:{course}</dc:identifier>
+    <dc:title>{course}</dc:title>
+    <dc:language>en</dc:language>
+    <meta property="dcterms:modified">{modified}</meta>
+  </metadata>
+  <manifest>
+{manifest}  </manifest>
+  <spine>
+{spine}  </spine>
+</package>
+"#,
+        course = escape(&pack.course),
+        modified = Utc::now().format("%Y-%m-%dT%H:%M:%SZ")
+    )
+}
+
+/// Writes the pack as an EPUB 3 book with one chapter per week.
+pub fn render(pack: &StudyPack, output: &Path) -> std::io::Result<()> {
+    let mut zip = ZipWriter::new(File::create(output)?);
+    let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    // the mimetype must come first and uncompressed for readers to recognize the file
+    zip.start_file("mimetype", SimpleFileOptions::default().compression_method(CompressionMethod::Stored))?;
+    zip.write_all(b"application/epub+zip")?;
+
+    zip.start_file("META-INF/container.xml", deflated)?;
+    zip.write_all(CONTAINER.as_bytes())?;
+
+    zip.start_file("OEBPS/content.opf", deflated)?;
+    zip.write_all(package(pack).as_bytes())?;
+
+    let toc = table_of_contents(pack, &|anchor, week| format!("week-{week}.xhtml#{anchor}"));
+    zip.start_file("OEBPS/nav.xhtml", deflated)?;
+    zip.write_all(xhtml(&pack.course, &format!("<h1>{}</h1>\n<nav epub:type=\"toc\">\n<h2>Contents</h2>\n{toc}</nav>\n", escape(&pack.course))).as_bytes())?;
+
+    for week in &pack.weeks {
+        let mut body = format!("<section id=\"{}\">\n<h2>{}</h2>\n", week.anchor(), week.title());
+        for page in &week.pages {
+            body += &format!("<article id=\"{}\">\n<h3>{}</h3>\n", page.anchor(), page.title());
+            body += &render_page(page, &|image| image_file(page, image));
+            body += "</article>\n";
+
+            for image in &page.images {
+                zip.start_file(format!("OEBPS/{}", image_file(page, image)), deflated)?;
+                zip.write_all(&std::fs::read(image)?)?;
+            }
+        }
+        body += "</section>\n";
+
+        zip.start_file(format!("OEBPS/{}", chapter_file(week)), deflated)?;
+        zip.write_all(xhtml(&week.title(), &body).as_bytes())?;
+    }
+
+    zip.finish()?;
+
+    return Ok(());
+}
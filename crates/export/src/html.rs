@@ -0,0 +1,90 @@
+use std::path::Path;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use pulldown_cmark::{CowStr, Event, HeadingLevel, Parser, Tag, TagEnd, html::push_html};
+
+use super::pack::{Page, StudyPack, markdown_options, mime_type};
+
+const STYLE: &str = "body { font-family: sans-serif; max-width: 50em; margin: 2em auto; padding: 0 1em; line-height: 1.5; }
+img { max-width: 100%; }
+figure { margin: 1em 0; }
+pre { background: #f4f4f4; padding: 0.5em; overflow-x: auto; }
+section.week { page-break-before: always; }";
+
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// course, week and session titles take the top three heading levels
+fn demote(level: HeadingLevel) -> HeadingLevel {
+    HeadingLevel::try_from((level as usize + 3).min(6)).unwrap_or(HeadingLevel::H6)
+}
+
+/// Renders a session note to HTML, pointing images at whatever `src` returns for their file.
+pub(crate) fn render_page(page: &Page, src: &dyn Fn(&Path) -> String) -> String {
+    let events = Parser::new_ext(&page.markdown, markdown_options()).map(|event| match event {
+        Event::Start(Tag::Heading { level, id, classes, attrs }) => Event::Start(Tag::Heading { level: demote(level), id, classes, attrs }),
+        Event::End(TagEnd::Heading(level)) => Event::End(TagEnd::Heading(demote(level))),
+        Event::Start(Tag::Image { link_type, dest_url, title, id }) => {
+            let dest_url = page.resolve_image(&dest_url).map_or(dest_url, |path| CowStr::from(src(&path)));
+            Event::Start(Tag::Image { link_type, dest_url, title, id })
+        },
+        event => event
+    });
+
+    let mut body = String::new();
+    push_html(&mut body, events);
+
+    for image in page.unreferenced_images() {
+        let name = escape(&image.file_name().unwrap_or_default().to_string_lossy());
+        body += &format!("<figure><img src=\"{}\" alt=\"{name}\" /><figcaption>{name}</figcaption></figure>\n", src(image));
+    }
+
+    return body;
+}
+
+fn data_uri(path: &Path) -> String {
+    match std::fs::read(path) {
+        Ok(bytes) => format!("data:{};base64,{}", mime_type(path), STANDARD.encode(bytes)),
+        Err(e) => {
+            eprintln!("Unable to embed \"{}\": {e:#}", path.to_string_lossy());
+            String::new()
+        }
+    }
+}
+
+/// Table of contents linking to each week and session
+pub(crate) fn table_of_contents(pack: &StudyPack, href: &dyn Fn(&str, u16) -> String) -> String {
+    let mut toc = String::from("<ol>\n");
+    for week in &pack.weeks {
+        toc += &format!("<li><a href=\"{}\">{}</a>\n<ol>\n", href(&week.anchor(), week.number), week.title());
+        for page in &week.pages {
+            toc += &format!("<li><a href=\"{}\">{}</a></li>\n", href(&page.anchor(), week.number), page.title());
+        }
+        toc += "</ol>\n</li>\n";
+    }
+
+    return toc + "</ol>\n";
+}
+
+/// Renders the whole pack as a standalone HTML document with images embedded.
+pub fn render(pack: &StudyPack) -> String {
+    let course = escape(&pack.course);
+    let mut html = format!("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\" />\n<title>{course}</title>\n<style>\n{STYLE}\n</style>\n</head>\n<body>\n<h1>{course}</h1>\n");
+
+    html += "<nav>\n<h2>Contents</h2>\n";
+    html += &table_of_contents(pack, &|anchor, _| format!("#{anchor}"));
+    html += "</nav>\n";
+
+    for week in &pack.weeks {
+        html += &format!("<section class=\"week\" id=\"{}\">\n<h2>{}</h2>\n", week.anchor(), week.title());
+        for page in &week.pages {
+            html += &format!("<article id=\"{}\">\n<h3>{}</h3>\n", page.anchor(), page.title());
+            html += &render_page(page, &data_uri);
+            html += "</article>\n";
+        }
+        html += "</section>\n";
+    }
+
+    return html + "</body>\n</html>\n";
+}
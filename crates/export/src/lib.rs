@@ -0,0 +1,63 @@
+use std::{fmt::Display, path::{Path, PathBuf}, str::FromStr};
+
+use data::Config;
+
+mod pack;
+pub use pack::{Page, StudyPack, Week};
+
+mod epub;
+mod html;
+mod pdf;
+pub use pdf::wrap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Format {
+    #[default]
+    Html,
+    Pdf,
+    Epub,
+}
+
+impl Format {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Html => "html",
+            Format::Pdf => "pdf",
+            Format::Epub => "epub",
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.extension())
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "html" | "htm" => Ok(Format::Html),
+            "pdf"          => Ok(Format::Pdf),
+            "epub"         => Ok(Format::Epub),
+            _ => Err(format!("Unknown export format \"{s}\" (expected html, pdf or epub)"))
+        }
+    }
+}
+
+/// Default location of an exported study pack, next to the course's week folders
+pub fn default_export_path(config: &Config, course: &str, format: Format) -> PathBuf {
+    config.get_root().join(course).join(format!("{course}.{}", format.extension()))
+}
+
+/// Writes every session note of `course` to `output` as a single document with a table of contents by week.
+pub fn export(config: &Config, course: &str, format: Format, output: &Path) -> std::io::Result<()> {
+    let pack = StudyPack::collect(config, course);
+
+    match format {
+        Format::Html => std::fs::write(output, html::render(&pack)),
+        Format::Pdf => pdf::render(&pack, output),
+        Format::Epub => epub::render(&pack, output),
+    }
+}
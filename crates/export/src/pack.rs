@@ -0,0 +1,112 @@
+use std::path::{Path, PathBuf};
+
+use data::Config;
+use helpers::{attach::is_image, frontmatter::strip, session::{Session, get_sessions}};
+use pulldown_cmark::{Event, Options, Parser, Tag};
+
+pub(crate) fn markdown_options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS | Options::ENABLE_WIKILINKS
+}
+
+pub(crate) fn mime_type(path: &Path) -> &'static str {
+    match path.extension().map(|e| e.to_string_lossy().to_ascii_lowercase()).as_deref() {
+        Some("png")          => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif")          => "image/gif",
+        Some("webp")         => "image/webp",
+        Some("svg")          => "image/svg+xml",
+        Some("bmp")          => "image/bmp",
+        _ => "application/octet-stream",
+    }
+}
+
+/// One session note along with the images in its folder.
+#[derive(Debug)]
+pub struct Page {
+    pub session: Session,
+    pub markdown: String,
+    pub images: Vec<PathBuf>,
+}
+
+impl Page {
+    /// Resolves an image reference in the note to a file in the session folder. References leading outside of
+    /// it, like absolute paths, `../` or symlinks, resolve to nothing, so exports can't pick up unrelated files.
+    pub fn resolve_image(&self, dest: &str) -> Option<PathBuf> {
+        let dir = self.session.dir.canonicalize().ok()?;
+        let path = dir.join(dest.replace("%20", " ")).canonicalize().ok()?;
+
+        let inside = path.strip_prefix(&dir).ok()?;
+        return path.is_file().then(|| self.session.dir.join(inside));
+    }
+
+    /// Images in the session folder that the note never embeds
+    pub fn unreferenced_images(&self) -> Vec<&PathBuf> {
+        let referenced = Parser::new_ext(&self.markdown, markdown_options())
+            .filter_map(|event| match event {
+                Event::Start(Tag::Image { dest_url, .. }) => self.resolve_image(&dest_url),
+                _ => None
+            })
+            .collect::<Vec<_>>();
+
+        self.images.iter().filter(|i| !referenced.contains(i)).collect()
+    }
+
+    pub fn title(&self) -> String {
+        format!("Class {} ({})", self.session.number, self.session.date)
+    }
+
+    pub fn anchor(&self) -> String {
+        format!("week-{}-class-{}", self.session.week, self.session.number)
+    }
+}
+
+#[derive(Debug)]
+pub struct Week {
+    pub number: u16,
+    pub pages: Vec<Page>,
+}
+
+impl Week {
+    pub fn title(&self) -> String {
+        format!("Week {}", self.number)
+    }
+
+    pub fn anchor(&self) -> String {
+        format!("week-{}", self.number)
+    }
+}
+
+/// A course's notes, grouped by week in chronological order.
+#[derive(Debug)]
+pub struct StudyPack {
+    pub course: String,
+    pub weeks: Vec<Week>,
+}
+
+impl StudyPack {
+    pub fn collect(config: &Config, course: &str) -> StudyPack {
+        let mut weeks: Vec<Week> = Vec::new();
+
+        for session in get_sessions(&config.get_root(), course) {
+            let markdown = std::fs::read_to_string(&session.note).map(|content| strip(&content).to_string()).unwrap_or_default();
+            let mut images = std::fs::read_dir(&session.dir)
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok)
+                .map(|e| e.path())
+                .filter(|p| is_image(p))
+                .collect::<Vec<_>>();
+            images.sort();
+
+            let page = Page { session, markdown, images };
+            match weeks.iter_mut().find(|w| w.number == page.session.week) {
+                Some(week) => week.pages.push(page),
+                None => weeks.push(Week { number: page.session.week, pages: vec![page] }),
+            }
+        }
+
+        weeks.sort_by_key(|w| w.number);
+
+        return StudyPack { course: course.to_string(), weeks };
+    }
+}
@@ -0,0 +1,316 @@
+use std::{collections::BTreeSet, fs::File, io::BufWriter, path::{Path, PathBuf}};
+
+use printpdf::{
+    BuiltinFont, Image, ImageTransform, IndirectFontRef, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference, PdfPageIndex,
+    image_crate::{self, DynamicImage},
+};
+use pulldown_cmark::{Event, HeadingLevel, Parser, Tag, TagEnd};
+
+use super::pack::{Page, StudyPack, markdown_options};
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 20.0;
+const BODY_SIZE: f32 = 11.0;
+const CODE_SIZE: f32 = 9.0;
+const PT_TO_MM: f32 = 0.3528;
+// the built-in fonts aren't measured, so assume an average glyph width relative to the font size
+const SANS_WIDTH: f32 = 0.5;
+const MONO_WIDTH: f32 = 0.6;
+
+#[derive(Debug)]
+enum Block {
+    Heading(HeadingLevel, String),
+    Paragraph(String),
+    Item(usize, String),
+    Code(String),
+    Rule,
+    Image(PathBuf),
+}
+
+fn heading_size(level: HeadingLevel) -> f32 {
+    match level {
+        HeadingLevel::H1 => 20.0,
+        HeadingLevel::H2 => 16.0,
+        HeadingLevel::H3 => 14.0,
+        _ => 12.0,
+    }
+}
+
+/// Flattens a note into the blocks this renderer knows how to lay out.
+fn blocks(page: &Page) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut text = String::new();
+    let mut depth = 0;
+    let mut in_code = false;
+    let mut in_image = false;
+
+    for event in Parser::new_ext(&page.markdown, markdown_options()) {
+        match event {
+            Event::Start(Tag::List(_)) => {
+                // a nested list ends the text of the item it's in
+                if !text.trim().is_empty() {
+                    blocks.push(Block::Item(depth, std::mem::take(&mut text)));
+                }
+                depth += 1;
+            },
+            Event::End(TagEnd::List(_)) => depth -= 1,
+            Event::Start(Tag::CodeBlock(_)) => in_code = true,
+            Event::Start(Tag::Image { dest_url, .. }) => {
+                in_image = true;
+                blocks.extend(page.resolve_image(&dest_url).map(Block::Image));
+            },
+            Event::End(TagEnd::Image) => in_image = false,
+            Event::Text(_) if in_image => {},
+            Event::Text(t) | Event::Code(t) => text += &t,
+            Event::SoftBreak | Event::HardBreak => text.push(if in_code { '\n' } else { ' ' }),
+            Event::TaskListMarker(done) => text += if done { "[x] " } else { "[ ] " },
+            Event::Rule => blocks.push(Block::Rule),
+            Event::End(end) => {
+                let content = std::mem::take(&mut text);
+                match end {
+                    TagEnd::Heading(level) => blocks.push(Block::Heading(level, content)),
+                    TagEnd::CodeBlock => {
+                        in_code = false;
+                        blocks.push(Block::Code(content));
+                    },
+                    TagEnd::Item if !content.trim().is_empty() => blocks.push(Block::Item(depth, content)),
+                    TagEnd::Paragraph if depth > 0 => text = content, // finished by the enclosing item
+                    TagEnd::Paragraph | TagEnd::TableRow | TagEnd::TableHead => blocks.push(Block::Paragraph(content)),
+                    TagEnd::TableCell => text = content + " | ",
+                    _ => text = content,
+                }
+            },
+            _ => {}
+        }
+    }
+
+    blocks.extend(page.unreferenced_images().into_iter().cloned().map(Block::Image));
+
+    return blocks;
+}
+
+/// Characters that fit on a line of text at `size`
+fn line_chars(size: f32, glyph_width: f32, indent: f32) -> usize {
+    ((PAGE_WIDTH - 2.0 * MARGIN - indent) / (size * PT_TO_MM * glyph_width)).max(1.0) as usize
+}
+
+/// Breaks `text` into lines of at most `max_chars` characters at spaces, splitting words longer than a line.
+pub fn wrap(text: &str, max_chars: usize) -> Vec<String> {
+    let max_chars = max_chars.max(1);
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split(' ') {
+            let mut word = word.chars().collect::<Vec<_>>();
+            if !line.is_empty() && line.chars().count() + word.len() >= max_chars {
+                lines.push(std::mem::take(&mut line));
+            }
+
+            // the line is empty by now, so a word this long gets lines of its own
+            while word.len() > max_chars {
+                lines.push(word.drain(..max_chars).collect());
+            }
+
+            if !line.is_empty() { line.push(' '); }
+            line.extend(word);
+        }
+        lines.push(line);
+    }
+
+    return lines;
+}
+
+/// Whether the built-in fonts can show `c`. They use WinAnsiEncoding: Latin-1 plus some typographic characters.
+fn encodable(c: char) -> bool {
+    matches!(c, ' '..='~' | '\u{a0}'..='\u{ff}') || "€‚ƒ„…†‡ˆ‰Š‹ŒŽ‘’“”•–—˜™š›œžŸ".contains(c)
+}
+
+struct Writer {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    page: PdfPageIndex,
+    // 1-indexed, for the table of contents
+    page_number: usize,
+    y: f32,
+    sans: IndirectFontRef,
+    bold: IndirectFontRef,
+    mono: IndirectFontRef,
+    // characters replaced because the built-in fonts can't show them
+    unencodable: BTreeSet<char>,
+}
+
+impl Writer {
+    fn new_page(&mut self) {
+        let (page, layer) = self.doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Notes");
+        self.page = page;
+        self.page_number += 1;
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    fn ensure_space(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.new_page();
+        }
+    }
+
+    fn line(&mut self, text: &str, size: f32, font: &IndirectFontRef, indent: f32) {
+        let height = size * PT_TO_MM * 1.4;
+        self.ensure_space(height);
+        self.y -= height;
+
+        let text = text.replace('\t', "    ").chars().map(|c| {
+            if encodable(c) { return c; }
+            self.unencodable.insert(c);
+            '?'
+        }).collect::<String>();
+        self.layer.use_text(text, size, Mm(MARGIN + indent), Mm(self.y), font);
+    }
+
+    fn image(&mut self, path: &Path) {
+        let image = match image_crate::open(path) {
+            Ok(image) => image,
+            Err(e) => {
+                eprintln!("Unable to embed \"{}\": {e:#}", path.to_string_lossy());
+                return;
+            }
+        };
+
+        // the PDF writer doesn't handle alpha channels
+        let image = DynamicImage::ImageRgb8(image.to_rgb8());
+        let dpi = 150.0;
+        let (width, height) = (image.width() as f32 / dpi * 25.4, image.height() as f32 / dpi * 25.4);
+        let scale = ((PAGE_WIDTH - 2.0 * MARGIN) / width).min((PAGE_HEIGHT - 2.0 * MARGIN) / height).min(1.0);
+
+        self.ensure_space(height * scale + 2.0);
+        self.y -= height * scale + 2.0;
+        Image::from_dynamic_image(&image).add_to_layer(self.layer.clone(), ImageTransform {
+            translate_x: Some(Mm(MARGIN)),
+            translate_y: Some(Mm(self.y)),
+            scale_x: Some(scale),
+            scale_y: Some(scale),
+            dpi: Some(dpi),
+            ..Default::default()
+        });
+    }
+
+    fn block(&mut self, block: &Block) {
+        match block {
+            Block::Heading(level, text) => {
+                let size = heading_size(*level);
+                self.ensure_space(size * PT_TO_MM * 3.0);
+                self.y -= 2.0;
+                for line in wrap(text, line_chars(size, SANS_WIDTH, 0.0)) {
+                    let font = self.bold.clone();
+                    self.line(&line, size, &font, 0.0);
+                }
+            },
+            Block::Paragraph(text) => {
+                for line in wrap(text, line_chars(BODY_SIZE, SANS_WIDTH, 0.0)) {
+                    let font = self.sans.clone();
+                    self.line(&line, BODY_SIZE, &font, 0.0);
+                }
+                self.y -= 1.5;
+            },
+            Block::Item(depth, text) => {
+                let indent = 6.0 * *depth as f32;
+                for (i, line) in wrap(text, line_chars(BODY_SIZE, SANS_WIDTH, indent)).iter().enumerate() {
+                    let font = self.sans.clone();
+                    self.line(&format!("{}{line}", if i == 0 { "- " } else { "  " }), BODY_SIZE, &font, indent);
+                }
+            },
+            Block::Code(text) => {
+                for line in wrap(text, line_chars(CODE_SIZE, MONO_WIDTH, 4.0)) {
+                    let font = self.mono.clone();
+                    self.line(&line, CODE_SIZE, &font, 4.0);
+                }
+                self.y -= 1.5;
+            },
+            Block::Rule => {
+                self.y -= 3.0;
+                let font = self.sans.clone();
+                self.line(&"_".repeat(60), BODY_SIZE, &font, 0.0);
+            },
+            Block::Image(path) => self.image(path),
+        }
+    }
+}
+
+fn font_error(e: printpdf::Error) -> std::io::Error {
+    std::io::Error::other(e.to_string())
+}
+
+/// Writes the pack as a PDF using the built-in PDF fonts, with a table of contents and bookmarks by week. Text
+/// outside of what those fonts can encode (see [`encodable`]) is replaced and reported.
+pub fn render(pack: &StudyPack, output: &Path) -> std::io::Result<()> {
+    let (doc, first_page, first_layer) = PdfDocument::new(&pack.course, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Contents");
+    let layer = doc.get_page(first_page).get_layer(first_layer);
+
+    let mut writer = Writer {
+        sans: doc.add_builtin_font(BuiltinFont::Helvetica).map_err(font_error)?,
+        bold: doc.add_builtin_font(BuiltinFont::HelveticaBold).map_err(font_error)?,
+        mono: doc.add_builtin_font(BuiltinFont::Courier).map_err(font_error)?,
+        doc,
+        layer,
+        page: first_page,
+        page_number: 1,
+        y: PAGE_HEIGHT - MARGIN,
+        unencodable: BTreeSet::new(),
+    };
+
+    // reserve enough pages up front for the contents, since pages can't be reordered afterwards
+    // the title and heading on the first page take up about as much room as four entries
+    let toc_entries = 4 + pack.weeks.iter().map(|w| 1 + w.pages.len()).sum::<usize>();
+    let entries_per_page = ((PAGE_HEIGHT - 2.0 * MARGIN) / (BODY_SIZE * PT_TO_MM * 1.4)) as usize;
+    let mut toc_pages = vec![(first_page, first_layer)];
+    for _ in 1..toc_entries.div_ceil(entries_per_page) {
+        toc_pages.push(writer.doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Contents"));
+        writer.page_number += 1;
+    }
+
+    let mut toc = Vec::new();
+    for week in &pack.weeks {
+        writer.new_page();
+        writer.doc.add_bookmark(week.title(), writer.page);
+        toc.push((week.title(), writer.page_number, 0.0));
+        writer.block(&Block::Heading(HeadingLevel::H1, week.title()));
+
+        for page in &week.pages {
+            writer.ensure_space(BODY_SIZE * PT_TO_MM * 6.0);
+            toc.push((page.title(), writer.page_number, 6.0));
+            writer.block(&Block::Heading(HeadingLevel::H2, page.title()));
+            for block in blocks(page) {
+                writer.block(&block);
+            }
+        }
+    }
+
+    // fill in the reserved contents pages now that page numbers are known
+    let mut entries = toc.iter();
+    for (i, (page, layer)) in toc_pages.iter().enumerate() {
+        writer.layer = writer.doc.get_page(*page).get_layer(*layer);
+        writer.y = PAGE_HEIGHT - MARGIN;
+
+        if i == 0 {
+            let bold = writer.bold.clone();
+            writer.line(&pack.course, heading_size(HeadingLevel::H1), &bold, 0.0);
+            writer.line("Contents", heading_size(HeadingLevel::H2), &bold, 0.0);
+        }
+
+        for (title, page, indent) in entries.by_ref().take(entries_per_page - if i == 0 { 4 } else { 0 }) {
+            let font = writer.sans.clone();
+            writer.line(&format!("{title} .... {page}"), BODY_SIZE, &font, *indent);
+        }
+    }
+
+    writer.doc.save(&mut BufWriter::new(File::create(output)?)).map_err(font_error)?;
+
+    if !writer.unencodable.is_empty() {
+        let missing = writer.unencodable.iter().map(char::to_string).collect::<Vec<_>>().join(" ");
+        eprintln!("The PDF fonts can't show some characters, so they were printed as \"?\": {missing}. Export to HTML or EPUB to keep them.");
+    }
+
+    return Ok(());
+}
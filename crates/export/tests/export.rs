@@ -0,0 +1,62 @@
+use std::path::Path;
+
+use chrono::NaiveDate;
+use data::Config;
+use export::{Format, Page, export, wrap};
+use helpers::session::Session;
+use tempfile::TempDir;
+
+// a made-up PNG; the HTML export embeds bytes without decoding them
+const IMAGE: &[u8] = b"\x89PNG\r\n\x1a\nnot really an image";
+
+fn session(root: &Path, markdown: &str) -> Session {
+    let dir = root.join("MATH/Week-1/Class-1-2026-09-07");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("board photo.png"), IMAGE).unwrap();
+    std::fs::write(dir.join("Class-1-2026-09-07.md"), markdown).unwrap();
+    std::fs::write(root.join("secret.png"), b"do not share").unwrap();
+
+    Session {
+        course: "MATH".into(),
+        week: 1,
+        number: 1,
+        date: NaiveDate::from_ymd_opt(2026, 9, 7).unwrap(),
+        note: dir.join("Class-1-2026-09-07.md"),
+        dir,
+    }
+}
+
+#[test]
+fn images_resolve_only_inside_the_session_folder() {
+    let root = TempDir::new().unwrap();
+    let session = session(root.path(), "");
+    let page = Page { session: session.clone(), markdown: String::new(), images: Vec::new() };
+
+    assert_eq!(page.resolve_image("board%20photo.png"), Some(session.dir.join("board photo.png")));
+    assert_eq!(page.resolve_image("missing.png"), None);
+    assert_eq!(page.resolve_image("../../../secret.png"), None);
+    assert_eq!(page.resolve_image(&root.path().join("secret.png").to_string_lossy()), None);
+}
+
+#[test]
+fn wrap_splits_words_longer_than_a_line() {
+    assert_eq!(wrap("a bb ccc", 4), vec!["a bb", "ccc"]);
+    assert_eq!(wrap("see https://example.com/long", 8), vec!["see", "https://", "example.", "com/long"]);
+    assert!(wrap(&"x".repeat(25), 10).iter().all(|line| line.chars().count() <= 10));
+}
+
+#[test]
+fn html_export_embeds_session_images_only() {
+    let root = TempDir::new().unwrap();
+    let config = Config::new(root.path().to_path_buf(), Vec::new(), "vim".into());
+    session(root.path(), "# MATH\n\nLimits.\n\n![board](board%20photo.png)\n\n![key](../../../secret.png)\n");
+
+    let output = root.path().join("MATH.html");
+    export(&config, "MATH", Format::Html, &output).unwrap();
+    let html = std::fs::read_to_string(&output).unwrap();
+
+    assert!(html.contains("<a href=\"#week-1-class-1\">Class 1 (2026-09-07)</a>"));
+    assert!(html.contains("<p>Limits.</p>"));
+    assert_eq!(html.matches("data:image/png;base64,").count(), 1);
+    assert!(html.contains("src=\"../../../secret.png\""));
+}
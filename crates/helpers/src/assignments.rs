@@ -0,0 +1,144 @@
+use std::path::Path;
+
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime, NaiveTime};
+use data::{Config, assignment::{Assignment, Assignments, Status}, time::Time};
+
+use super::note::get_current_class;
+use super::session::{find_course, get_sessions};
+
+const ASSIGNMENTS_FILE: &str = "assignments.toml";
+/// How far ahead "due soon" looks
+pub const DUE_SOON_DAYS: i64 = 7;
+
+/// Reads `assignments.toml` from the notes root, or nothing if there isn't one yet.
+pub fn read_assignments(config: &Config) -> Result<Assignments, String> {
+    let path = config.get_root().join(ASSIGNMENTS_FILE);
+    if !path.exists() {
+        return Ok(Assignments::default());
+    }
+
+    let content = std::fs::read_to_string(&path).map_err(|e| format!("Unable to read \"{}\": {e:#}", path.to_string_lossy()))?;
+    return toml::from_str(&content).map_err(|e| format!("Unable to parse \"{}\": {e}", path.to_string_lossy()));
+}
+
+pub fn write_assignments(config: &Config, assignments: &Assignments) -> std::io::Result<()> {
+    std::fs::write(config.get_root().join(ASSIGNMENTS_FILE), toml::to_string(assignments).map_err(std::io::Error::other)?)
+}
+
+/// Adds an assignment to `course`, or to the class in session, linked to the course's latest note.
+pub fn add_assignment(config: &Config, title: String, course: Option<&str>, due: NaiveDateTime) -> Result<Assignment, String> {
+    let course = match course {
+        Some(name) => config.get_classes()
+            .iter()
+            .find(|c| c.get_name().eq_ignore_ascii_case(name))
+            .map(|c| c.get_name().clone())
+            .or_else(|| find_course(config, name))
+            .ok_or(format!("No course named \"{name}\" found."))?,
+        None => get_current_class(config, Time::now()).map_err(|e| format!("{e} Name the course with --course."))?.0.get_name().clone(),
+    };
+
+    let root = config.get_root();
+    let note = get_sessions(&root, &course)
+        .into_iter()
+        .rfind(|s| s.note.is_file())
+        .and_then(|s| s.note.strip_prefix(&root).ok().map(Path::to_path_buf));
+
+    let mut assignments = read_assignments(config)?;
+    let added = assignments.add(title, course, due, note).clone();
+    write_assignments(config, &assignments).map_err(|e| format!("Unable to save assignments: {e:#}"))?;
+
+    return Ok(added);
+}
+
+/// Marks assignment `id` as done.
+pub fn complete_assignment(config: &Config, id: u32) -> Result<Assignment, String> {
+    let mut assignments = read_assignments(config)?;
+    let completed = assignments.complete(id).cloned().ok_or(format!("No assignment #{id}."))?;
+    write_assignments(config, &assignments).map_err(|e| format!("Unable to save assignments: {e:#}"))?;
+
+    return Ok(completed);
+}
+
+/// Parses a due date: `today`, `tomorrow`, `YYYY-MM-DD` (due at the end of the day) or `YYYY-MM-DD HH:MM`.
+pub fn parse_due(input: &str) -> Result<NaiveDateTime, String> {
+    let end_of_day = NaiveTime::from_hms_opt(23, 59, 0).unwrap_or_default();
+    let today = Local::now().date_naive();
+
+    let input = input.trim();
+    match input.to_ascii_lowercase().as_str() {
+        "today" => return Ok(today.and_time(end_of_day)),
+        "tomorrow" => return Ok((today + Duration::days(1)).and_time(end_of_day)),
+        _ => {}
+    }
+
+    if let Ok(due) = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M") {
+        return Ok(due);
+    }
+
+    return NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .map(|date| date.and_time(end_of_day))
+        .map_err(|_| format!("\"{input}\" isn't a due date. Use YYYY-MM-DD, \"YYYY-MM-DD HH:MM\", today or tomorrow."));
+}
+
+/// How long until (or since) `due`, e.g. "in 3 days" or "2 hours ago"
+pub fn describe_due(due: NaiveDateTime, now: NaiveDateTime) -> String {
+    let (delta, overdue) = if due >= now { (due - now, false) } else { (now - due, true) };
+
+    let amount = match (delta.num_days(), delta.num_hours(), delta.num_minutes()) {
+        (days, ..) if days > 1 => format!("{days} days"),
+        (1, ..) => "1 day".into(),
+        (_, hours, _) if hours > 1 => format!("{hours} hours"),
+        (_, 1, _) => "1 hour".into(),
+        (.., minutes) => format!("{minutes} minutes"),
+    };
+
+    return if overdue { format!("{amount} ago") } else { format!("in {amount}") };
+}
+
+fn describe(assignment: &Assignment, now: NaiveDateTime) -> String {
+    format!("#{id} {course}: {title}, due {due} ({relative}){done}",
+        id = assignment.id,
+        course = assignment.course,
+        title = assignment.title,
+        due = assignment.due.format("%a %Y-%m-%d %H:%M"),
+        relative = describe_due(assignment.due, now),
+        done = if assignment.status == Status::Done { " [done]" } else { "" }
+    )
+}
+
+/// Prints assignments one per line, with the note each was assigned in.
+pub fn print_assignments(root: &Path, assignments: &[&Assignment]) {
+    let now = Local::now().naive_local();
+
+    if assignments.is_empty() {
+        println!("No assignments.");
+        return;
+    }
+
+    for assignment in assignments {
+        println!("{}", describe(assignment, now));
+        if let Some(note) = &assignment.note {
+            println!("\tassigned in {}", root.join(note).to_string_lossy());
+        }
+    }
+}
+
+/// Prints the unfinished assignments of `course` due within [`DUE_SOON_DAYS`], if there are any.
+pub fn print_due_soon(config: &Config, course: &str) {
+    let assignments = match read_assignments(config) {
+        Ok(assignments) => assignments,
+        Err(e) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+
+    let now = Local::now().naive_local();
+    let due = assignments.due_before(course, now + Duration::days(DUE_SOON_DAYS));
+    if due.is_empty() { return; }
+
+    println!("Due soon for {course}:");
+    for assignment in due {
+        println!("\t{}", describe(assignment, now));
+    }
+}
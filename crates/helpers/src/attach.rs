@@ -0,0 +1,141 @@
+use std::{fs::File, path::{Path, PathBuf}};
+
+use data::{Config, editor::Preset, obsidian::file_in_vault};
+use sha2::{Digest, Sha256};
+
+use super::note::{append_to_notes, get_current_session_note};
+use super::session::{find_course, find_session, get_sessions};
+
+pub const IMAGE_EXTENSIONS: [&str; 7] = ["png", "jpg", "jpeg", "gif", "webp", "svg", "bmp"];
+
+pub fn is_image(path: &Path) -> bool {
+    path.extension().is_some_and(|e| IMAGE_EXTENSIONS.contains(&e.to_string_lossy().to_ascii_lowercase().as_str()))
+}
+
+fn hash(path: &Path) -> std::io::Result<[u8; 32]> {
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut File::open(path)?, &mut hasher)?;
+
+    return Ok(hasher.finalize().into());
+}
+
+// a file in `dir` with the same content as `file`, comparing sizes first to avoid hashing everything
+fn find_duplicate(dir: &Path, file: &Path, file_hash: &[u8; 32]) -> Option<PathBuf> {
+    let size = std::fs::metadata(file).ok()?.len();
+
+    std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && std::fs::metadata(p).is_ok_and(|m| m.len() == size))
+        .find(|p| hash(p).is_ok_and(|h| &h == file_hash))
+}
+
+// `name`, or `name-1`, `name-2`... if a different file already has it
+fn unique_path(dir: &Path, name: &Path) -> PathBuf {
+    let stem = name.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = name.extension().map(|e| format!(".{}", e.to_string_lossy())).unwrap_or_default();
+
+    let mut path = dir.join(name);
+    let mut i = 1;
+    while path.exists() {
+        path = dir.join(format!("{stem}-{i}{extension}"));
+        i += 1;
+    }
+
+    return path;
+}
+
+fn move_file(from: &Path, to: &Path) -> std::io::Result<()> {
+    // renaming fails across filesystems
+    if std::fs::rename(from, to).is_err() {
+        std::fs::copy(from, to)?;
+        std::fs::remove_file(from)?;
+    }
+
+    return Ok(());
+}
+
+/// Link embedding `file` in a note, as an Obsidian embed when notes are opened in Obsidian.
+fn embed(config: &Config, file: &Path) -> String {
+    let name = file.file_name().unwrap_or_default().to_string_lossy().to_string();
+
+    let editor = config.get_editor();
+    if let Some(uri) = editor.obsidian() {
+        // attachments often share names across sessions, so point at this one by its path in the vault
+        let target = uri.resolve_vault_path().and_then(|vault| file_in_vault(&vault, file)).unwrap_or(name);
+        return format!("![[{target}]]");
+    }
+
+    if editor.preset() == Some(Preset::Obsidian) {
+        return format!("![[{name}]]");
+    }
+
+    if is_image(file) {
+        let stem = file.file_stem().unwrap_or_default().to_string_lossy();
+        return format!("![{stem}](<{name}>)");
+    }
+
+    return format!("[{name}](<{name}>)");
+}
+
+/// The note to attach to: a session of `course` (its latest if `session` isn't given), or today's note of the class in session.
+pub fn target_note(config: &Config, course: Option<&str>, session: Option<&str>) -> Result<PathBuf, String> {
+    let Some(name) = course else {
+        return get_current_session_note(config).map(|(path, ..)| path).map_err(|e| format!("{e} Name a course to attach to instead."));
+    };
+
+    let root = config.get_root();
+    let course = find_course(config, name).ok_or(format!("No notes found for \"{name}\"."))?;
+
+    let found = match session {
+        Some(query) => find_session(&root, &course, query).ok_or(format!("No session \"{query}\" found in {course}."))?,
+        None => get_sessions(&root, &course).pop().ok_or(format!("{course} has no sessions yet."))?,
+    };
+
+    return Ok(found.note);
+}
+
+/// Copies (or moves) `files` into the folder of `note` and links them at the end of its Notes section.
+/// A file whose content is already in the folder is linked to the existing copy instead of being added again.
+pub fn attach(config: &Config, note: &Path, files: &[PathBuf], move_files: bool) -> std::io::Result<()> {
+    let dir = note.parent().unwrap_or(Path::new("."));
+    let content = std::fs::read_to_string(note).unwrap_or_default();
+    let mut links = Vec::new();
+
+    for file in files {
+        if !file.is_file() {
+            eprintln!("Skipping \"{}\", which isn't a file.", file.to_string_lossy());
+            continue;
+        }
+
+        let file_hash = hash(file)?;
+        let attached = match find_duplicate(dir, file, &file_hash) {
+            Some(existing) => {
+                println!("\"{}\" is already attached as \"{}\".", file.to_string_lossy(), existing.file_name().unwrap_or_default().to_string_lossy());
+                // the session folder has an identical copy, so nothing is lost
+                if move_files && std::fs::canonicalize(file)? != std::fs::canonicalize(&existing)? {
+                    std::fs::remove_file(file)?;
+                }
+                existing
+            },
+            None => {
+                let destination = unique_path(dir, Path::new(file.file_name().unwrap_or_default()));
+                if move_files { move_file(file, &destination)?; } else { std::fs::copy(file, &destination)?; }
+                println!("Attached \"{}\".", destination.file_name().unwrap_or_default().to_string_lossy());
+                destination
+            }
+        };
+
+        let link = embed(config, &attached);
+        if !content.contains(&link) && !links.contains(&link) {
+            links.push(link);
+        }
+    }
+
+    if !links.is_empty() {
+        append_to_notes(note, &links.join("\n\n"))?;
+    }
+
+    return Ok(());
+}
@@ -0,0 +1,131 @@
+use std::{collections::HashSet, path::PathBuf};
+
+use chrono::{Datelike, NaiveDate};
+use data::{Config, class::Class, semester::Semester, time::{Day, Times}};
+use serde::Serialize;
+
+use super::moc::link_course;
+use super::note::{TEMPLATE_HEADINGS, sessions_per_week, write_session_note};
+use super::session::{Session, get_sessions};
+
+/// A scheduled session without a folder, numbered and filed into a week as if its note had been taken that day
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Missed {
+    pub date: NaiveDate,
+    pub week: u16,
+    pub number: u16,
+}
+
+/// `attendance` output
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Attendance {
+    pub course: String,
+    pub scheduled: usize,
+    pub recorded: usize,
+    pub missed: Vec<Missed>,
+}
+
+/// Days `class` meets on in `semester` before `until`, skipping holidays. Asynchronous classes have none.
+pub fn scheduled_dates(class: &Class, semester: &Semester, until: NaiveDate) -> Vec<NaiveDate> {
+    let Times::At(times) = class.get_times() else { return Vec::new(); };
+    let days = times.iter().map(|t| t.start.day).collect::<HashSet<Day>>();
+
+    semester.start
+        .iter_days()
+        .take_while(|date| *date <= semester.end && *date < until)
+        .filter(|date| semester.in_session(*date) && days.contains(&date.weekday().into()))
+        .collect()
+}
+
+// every session of `class` once `missed` is filled in, in date order, with the week and number it belongs at:
+// numbered from 1 and grouped into weeks the same way `session_note_on` files new notes
+fn placements(class: &Class, sessions: Vec<Session>, missed: &[NaiveDate]) -> Vec<(NaiveDate, u16, u16, Option<Session>)> {
+    let mut dated = sessions.into_iter().map(|s| (s.date, Some(s))).chain(missed.iter().map(|d| (*d, None))).collect::<Vec<_>>();
+    dated.sort_by_key(|(date, session)| (*date, session.as_ref().map(|s| s.number)));
+
+    let per_week = sessions_per_week(class);
+    return dated
+        .into_iter()
+        .enumerate()
+        .map(|(i, (date, session))| (date, (i / per_week + 1) as u16, (i + 1) as u16, session))
+        .collect();
+}
+
+/// Compares the meetings of `class` before `today` with its session folders.
+pub fn attendance(config: &Config, class: &Class, semester: &Semester, today: NaiveDate) -> Attendance {
+    let scheduled = scheduled_dates(class, semester, today);
+    let sessions = get_sessions(&config.get_root(), class.get_name());
+    let taken = sessions.iter().map(|s| s.date).collect::<HashSet<_>>();
+    let missed_dates = scheduled.iter().filter(|date| !taken.contains(date)).copied().collect::<Vec<_>>();
+
+    let missed = placements(class, sessions, &missed_dates)
+        .into_iter()
+        .filter(|(.., session)| session.is_none())
+        .map(|(date, week, number, _)| Missed { date, week, number })
+        .collect::<Vec<_>>();
+
+    return Attendance {
+        course: class.get_name().clone(),
+        scheduled: scheduled.len(),
+        recorded: scheduled.len() - missed.len(),
+        missed,
+    };
+}
+
+// moves `session` to `week` as session `number`, renaming its note (and the note's title) to match
+fn renumber(config: &Config, session: &Session, week: u16, number: u16) -> std::io::Result<()> {
+    let name = format!("Class-{number}-{}", session.date.format("%Y-%m-%d"));
+    let week_dir = config.get_root().join(&session.course).join(format!("Week-{week}"));
+    std::fs::create_dir_all(&week_dir)?;
+
+    let dir = week_dir.join(&name);
+    std::fs::rename(&session.dir, &dir)?;
+    // the week folder it came from may be left empty
+    let _ = session.dir.parent().map(std::fs::remove_dir);
+
+    let old_note = dir.join(session.note.file_name().unwrap_or_default());
+    if !old_note.is_file() { return Ok(()); }
+
+    let content = std::fs::read_to_string(&old_note)?;
+    std::fs::remove_file(&old_note)?;
+    std::fs::write(dir.join(format!("{name}.md")), content.replacen(&session.name(), &name, 1))
+}
+
+/// Line placeholder notes start with
+pub const PLACEHOLDER: &str = "> Missed. Copy notes from a classmate here.";
+
+fn placeholder_template(class: &Class, session_name: &str) -> String {
+    format!("# {} - {session_name}\n\n{PLACEHOLDER}\n\n{}\n\n", class.get_name(), TEMPLATE_HEADINGS.join("\n\n"))
+}
+
+/// Creates a note for each missed session, saying it was missed. Sessions after a missed one move up a number
+/// (and maybe a week) to keep the course numbered in date order.
+pub fn create_placeholders(config: &Config, class: &Class, missed: &[Missed]) -> Result<Vec<PathBuf>, String> {
+    let sessions = get_sessions(&config.get_root(), class.get_name());
+    let dates = missed.iter().map(|m| m.date).collect::<Vec<_>>();
+    let mut notes = Vec::new();
+
+    for (date, week, number, session) in placements(class, sessions, &dates) {
+        match session {
+            Some(session) if (session.week, session.number) != (week, number) => renumber(config, &session, week, number)
+                .map_err(|e| format!("Unable to renumber \"{}\": {e:#}", session.dir.to_string_lossy()))?,
+            Some(_) => {},
+            None => notes.push(write_session_note(config, class, week, number, date, placeholder_template)
+                .map_err(|e| format!("Unable to create a placeholder for {date}: {e:#}"))?),
+        }
+    }
+
+    if let Err(e) = link_course(config, class.get_name()) {
+        eprintln!("Unable to update links of {}: {e:#}", class.get_name());
+    }
+
+    return Ok(notes);
+}
+
+pub fn print_attendance(attendance: &Attendance) {
+    println!("{}: {} of {} sessions recorded.", attendance.course, attendance.recorded, attendance.scheduled);
+
+    for missed in &attendance.missed {
+        println!("  Missed Class {} (week {}): {} {}", missed.number, missed.week, missed.date.weekday(), missed.date);
+    }
+}
@@ -1,104 +1,106 @@
 use std::fs::{File, ReadDir, create_dir_all};
 use std::io::{Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 use data::class::Class;
-use data::obsidian::ObsidianPath;
+use data::obsidian::{ObsidianPath, discover_vaults};
 use data::time::{ClassTime, Day, Time, Times};
+use data::editor::Preset;
 use data::{Config, Location, Editor};
 
-use super::io::{demand_stdin, prompt};
-use super::print_flush;
+use super::io::{Prompter, Stdin};
 
 use super::parse_time;
 
-// change all to post-pub reexport?
+/// Parses meeting days like `M/W/F`.
+pub fn parse_days(input: &str) -> Result<Vec<Day>, String> {
+    input.split('/').map(str::parse).collect()
+}
+
+/// `previous` moved to `day`, still running past midnight if it did.
+pub fn same_time_on(previous: &ClassTime, day: Day) -> ClassTime {
+    let mut time = previous.clone();
+    [time.start.day, time.end.day] = [day, if previous.start.day != previous.end.day { day.tomorrow() } else { day }];
+
+    return time;
+}
+
+/// Names of the folders in `dir`, each taken to be a course.
+pub fn course_dirs(dir: ReadDir) -> Vec<String> {
+    let mut names = dir
+        .filter_map(Result::ok)
+        .filter(|f| f.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|f| f.file_name().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+
+    names.sort();
+
+    return names;
+}
+
+/// Asks for the time and place of `of` on each of `days`, offering to reuse the previous day's.
+fn get_times(p: &mut dyn Prompter, of: &str, days: &[Day]) -> Vec<ClassTime> {
+    let mut times: Vec<ClassTime> = Vec::new();
+
+    for &day in days.iter().filter(|&&d| d != Day::Async) {
+        if let Some(previous) = times.last() {
+            let question = format!("Would you like to set {of} on {day} to the last time ({} - {})? [Y/n]: ", previous.start.hms_string(), previous.end.hms_string());
+            if p.confirm(&question, true) {
+                times.push(same_time_on(previous, day));
+                continue;
+            }
+        }
+
+        let start = get_time(p, "start", day, None);
+        let end = get_time(p, "end", day, Some(start));
 
-fn get_class(name: String) -> Class {
-    let professor = demand_stdin(&format!("{} professor", name));        
+        times.push((start, end, get_location(p, of, day)).into());
+    }
+
+    return times;
+}
+
+/// Asks for the professor, meeting times and office hours of the class `name`.
+pub fn get_class(p: &mut dyn Prompter, name: String) -> Class {
+    let professor = p.demand(&format!("{name} professor"));
     let mut times: Vec<ClassTime> = Default::default();
-    let mut office_hours: Vec<ClassTime>  = Default::default();
+    let mut office_hours: Vec<ClassTime> = Default::default();
 
     loop {
-        let input = prompt!("Which days does {name} meet? (M/T/W/Th/F/Sat/Sun, or empty for Async): ");
+        let input = p.ask(&format!("Which days does {name} meet? (M/T/W/Th/F/Sat/Sun, or empty for Async): "));
         if input.is_empty() {
-            println!("Marking {name} as asynchronous.");
+            p.say(&format!("Marking {name} as asynchronous."));
             break;
         }
 
-        let days: Vec<Day> = match input.split('/').map(str::parse).collect() {
+        let days = match parse_days(&input) {
             Ok(d) => d,
             Err(e) => {
-                eprintln!("{e}");
+                p.warn(&e);
                 continue;
             }
         };
 
-        println!("Now setting meeting times for {name} on {}...", days.iter().map(Day::to_string).collect::<Vec<_>>().join("/"));
-
-        let mut prev_time = ClassTime::default();
-        for day in days {
-            if day == Day::Async { continue; }
-            if prev_time != ClassTime::default() {
-                let input = prompt!("Would you like to set the {day} meeting to the last time ({} - {})? [Y/n]: ", prev_time.start.hms_string(), prev_time.end.hms_string());
-
-                if input.is_empty() || input.to_ascii_lowercase().starts_with('y') {
-                    let mut new_time = prev_time.clone();
-                    [new_time.start.day, new_time.end.day] = [day, if prev_time.start.day != prev_time.end.day { day.tomorrow() } else { day } ];
-                    times.push(new_time);
-                    continue;
-                }
-            }
-
-            let new_start = get_time("start", day, None);
-            let new_end = get_time("end", day, Some(new_start.clone()));
-
-            prev_time = (new_start, new_end, get_location(&name, day)).into();
-            times.push(prev_time.clone());
-        }
+        p.say(&format!("Now setting meeting times for {name} on {}...", days.iter().map(Day::to_string).collect::<Vec<_>>().join("/")));
+        times = get_times(p, &format!("the {name} meeting"), &days);
 
         break;
     }
 
-    // print saved times
-
-    let input = prompt!("Would you like to enter office hours of {professor} for {name}? [Y/n]: ");
-
-    if input.is_empty() || input.to_ascii_lowercase().starts_with('y') {
+    if p.confirm(&format!("Would you like to enter office hours of {professor} for {name}? [Y/n]: "), true) {
         loop {
-            let input = prompt!("Which days are the office hours of {professor}? (M/T/W/Th/F/Sat/Sun): ");
-            let days: Vec<Day> = match input.split('/').map(str::parse).collect::<Result<Vec<_>, _>>() {
-                Ok(i) => i,
+            let input = p.ask(&format!("Which days are the office hours of {professor}? (M/T/W/Th/F/Sat/Sun): "));
+            let days = match parse_days(&input) {
+                Ok(d) => d,
                 Err(e) => {
-                    eprintln!("{e}");
+                    p.warn(&e);
                     continue;
                 }
             };
 
-            print_flush!("Now setting office hours for {professor} on {}...", days.iter().map(Day::to_string).collect::<Vec<_>>().join("/"));
-
-            let prev_time = ClassTime::default();
-            for day in days {
-                if day == Day::Async { continue; }
-                if prev_time != ClassTime::default() {
-                    let input = prompt!("Would you like to set {professor}'s {day} office hours to the last submitted time ({}:{}:{} - {}:{}:{})? [Y/n]: ",
-                        prev_time.start.hour, prev_time.start.minute, prev_time.start.second,
-                        prev_time.end.hour,   prev_time.end.minute,   prev_time.end.second
-                    );
-
-                    if input.is_empty() || input.to_ascii_lowercase().starts_with('y') {
-                        let mut new_time = prev_time.clone();
-                        [new_time.start.day, new_time.end.day] = [day, if prev_time.start.day != prev_time.end.day { day.tomorrow() } else { day } ];
-                        office_hours.push(new_time);
-                        continue;
-                    }
-                }
-
-                let new_start = get_time("start", day, None);
-                let new_end = get_time("end", day, Some(new_start.clone()));
-
-                office_hours.push((new_start, new_end, get_location(&format!("{professor}'s office hours"), day)).into());
-            }
+            p.say(&format!("Now setting office hours for {professor} on {}...", days.iter().map(Day::to_string).collect::<Vec<_>>().join("/")));
+            office_hours = get_times(p, &format!("{professor}'s office hours"), &days);
 
             break;
         }
@@ -107,52 +109,50 @@ fn get_class(name: String) -> Class {
     return Class::new(name, professor, Times::from(times), Times::from(office_hours));
 }
 
-fn build_config_from_dir(dir: ReadDir) -> Config {
-    let class_names = dir
-        .filter_map(Result::ok)
-        .filter_map(|f| 
-            f
-                .file_type()
-                .ok()
-                .and_then(|t| 
-                    t
-                        .is_dir()
-                        .then_some(f)
-                )
-        )
-        .map(|f|
-            f
-                .file_name()
-                .to_string_lossy()
-                .to_string()
-        )
-        .collect::<Vec<_>>()
-    ;
+/// Asks for the editor to open notes with. `default_editor` (usually `$EDITOR`) is used unless the user wants another.
+fn get_editor(p: &mut dyn Prompter, root: &Path, default_editor: Option<String>) -> Editor {
+    let program = match default_editor {
+        Some(program) if !p.confirm("Would you like to set a custom editor? [y/N]: ", false) => program,
+        _ => {
+            let presets = Preset::ALL.iter().map(Preset::to_string).collect::<Vec<_>>().join(", ");
+            p.required(&format!("Editor program, a preset ({presets}), or a command like \"nvim +{{line}} {{path}}\": "))
+        }
+    };
 
+    return match program.parse::<Preset>() {
+        Ok(Preset::Obsidian) => Editor::Complex { uri: choose_vault(p, root), program: "obsidian".into() },
+        Ok(_) => Editor::Custom { command: program, wait: None },
+        Err(_) if program.contains('{') => {
+            let wait = p.confirm("Does this editor keep running until you close the note? (No for most GUI editors) [Y/n]: ", true);
+            Editor::Custom { command: program, wait: Some(wait) }
+        },
+        Err(_) => Editor::Simple(program)
+    };
+}
+
+/// Builds a config with a class for every folder in `dir`, asking for each one's details.
+pub fn build_config_from_dir(p: &mut dyn Prompter, dir: ReadDir, default_editor: Option<String>) -> Config {
     let mut config = Config::default();
 
-    for name in class_names {
-        config.add_class(get_class(name));
+    for name in course_dirs(dir) {
+        config.add_class(get_class(p, name));
     }
 
     let mut root = std::env::current_dir().unwrap_or_default();
-    
-    let should_prompt_path = if !root.as_os_str().is_empty() {
-        let input = prompt!("Would you like to set a new path to create new notes in? (N = set current directory as path) [y/N]: ");
 
-        input.to_ascii_lowercase().starts_with('y')
-    } else { true };
+    let should_prompt_path = root.as_os_str().is_empty()
+        || p.confirm("Would you like to set a new path to create new notes in? (N = set current directory as path) [y/N]: ", false);
 
     if should_prompt_path {
         loop {
-            let input = prompt!("Enter new notes path: ");
+            let input = p.ask("Enter new notes path: ");
 
             if !std::fs::exists(&input).unwrap_or_default() {
-                println!("Unable to find \"{input}\". Please try again.");
+                p.say(&format!("Unable to find \"{input}\". Please try again."));
                 continue;
             }
 
-            println!("Setting path to \"{input}\".");
+            p.say(&format!("Setting path to \"{input}\"."));
 
             root = PathBuf::from(input);
 
@@ -160,37 +160,41 @@ fn build_config_from_dir(dir: ReadDir) -> Config {
         }
     }
 
-    let mut program = std::env::var("EDITOR").unwrap_or_default();
-    let should_prompt = if !program.is_empty() {
-        let input = prompt!("Would you like to set a custom editor? [y/N]: ");
-        
-        input.to_ascii_lowercase().starts_with('y')
-    } else { true };
-    
-    if should_prompt { program = prompt!(required, "Editor program: "); }
-
-    let editor = match program.as_str() {
-        "obsidian" => {
-            let vault = prompt!(required, "Which vault do you want to use?");
-            // todo: Implement Editor::new or from
-            Editor::Complex {
-                program,
-                uri: ObsidianPath {
-                    courses_root: root.clone(),
-                    vault
-                }
-            }
-            // ! ask obsidian if it exists
-        }
-        _ => Editor::Simple(program)
-    };
-
-    config.set_editor(editor);
+    config.set_editor(get_editor(p, &root, default_editor));
     config.set_root(root);
 
     return config;
+}
+
+// Obsidian can only open notes inside a vault, so make sure the notes path is in the chosen one
+fn choose_vault(p: &mut dyn Prompter, root: &Path) -> ObsidianPath {
+    let root = std::fs::canonicalize(root).unwrap_or(root.to_path_buf());
+    let vaults = discover_vaults();
+
+    if vaults.is_empty() {
+        p.say("Unable to find Obsidian's list of vaults, so the notes path can't be checked against the vault.");
+        let vault = p.required("Which vault do you want to use? ");
+        return ObsidianPath { vault, courses_root: root, vault_path: None };
+    }
 
-    // traverse each folder individually and correlate days into class days
+    if let Some(vault) = vaults.iter().find(|v| v.contains(&root)) {
+        p.say(&format!("Using the \"{}\" vault, which contains \"{}\".", vault.name(), root.to_string_lossy()));
+        return ObsidianPath { vault: vault.name(), courses_root: root, vault_path: Some(vault.path.clone()) };
+    }
+
+    let items = vaults.iter().map(|v| format!("{} ({})", v.name(), v.path.to_string_lossy())).collect::<Vec<_>>();
+    loop {
+        let Some(i) = p.pick("Which vault do you want to use?", &items) else {
+            let vault = p.required("Name of the vault to use: ");
+            return ObsidianPath { vault, courses_root: root, vault_path: None };
+        };
+        let vault = &vaults[i];
+
+        p.say(&format!("\"{}\" isn't inside the \"{}\" vault, so Obsidian won't be able to open notes there.", root.to_string_lossy(), vault.name()));
+        if p.confirm("Use it anyway? [y/N]: ", false) {
+            return ObsidianPath { vault: vault.name(), courses_root: root, vault_path: Some(vault.path.clone()) };
+        }
+    }
 }
 
 fn write_config<'file>(config: &Config, file: &mut File) -> Result<(), &'file str> {
@@ -205,6 +209,39 @@ fn write_config<'file>(config: &Config, file: &mut File) -> Result<(), &'file st
     Ok(())
 }
 
+/// The first-run wizard, building a config from an existing notes folder or from scratch. `None` if the user wants neither.
+pub fn setup_wizard(p: &mut dyn Prompter, default_editor: Option<String>) -> Option<Config> {
+    if p.confirm("No config found. Would you like to build a config from an existing directory? [y/N]: ", false) {
+        loop {
+            let path = p.ask("Please enter path, or leave blank to read current directory, or c to cancel: ");
+            if path == "c" { break; }
+
+            let path = if path.is_empty() { std::env::current_dir().unwrap_or_default() } else { PathBuf::from(path) };
+            if !path.exists() {
+                p.warn(&format!("\"{}\" does not appear to exist. Please try again...", path.to_string_lossy()));
+                continue;
+            }
+
+            let dir = match std::fs::read_dir(&path) {
+                Ok(dir) => dir,
+                Err(e) => {
+                    p.warn(&format!("Unable to read \"{}\" as a directory, please try again: {e:#}", path.to_string_lossy()));
+                    continue;
+                }
+            };
+
+            return Some(build_config_from_dir(p, dir, default_editor));
+        }
+    }
+
+    // find local classes/weeks
+    if p.confirm("Would you like to create a new config and add classes now? [Y/n]: ", true) {
+        return Some(init_classes(p, default_editor));
+    }
+
+    return None;
+}
+
 pub fn read_or_init_config(file: &mut File) -> Config {
     let mut content = String::new();
     file.read_to_string(&mut content).expect("Encountered issue when accessing config file.");
@@ -212,47 +249,15 @@ pub fn read_or_init_config(file: &mut File) -> Config {
     content = content.trim().to_string();
 
     // if no config
-    if content.len() == 0 {
-        let response = prompt!("No config found. Would you like to build a config from an existing directory? [y/N]: ");
-
-        if response.to_ascii_lowercase().starts_with('y') {
-            loop {
-                let path = prompt!("Please enter path, or leave blank to read current directory, or c to cancel: ");
-                if path == "c" { break; }
-                let dir = if path.len() == 0 { std::env::current_dir().expect("Unable to get current directory.").read_dir().unwrap() } else { 
-                    if !std::fs::exists(std::path::absolute(&path).expect("Unable to normalize path.")).expect("Unable to check for dir existence.") {
-                        eprintln!("\"{path}\" does not appear to exist. Please try again...");
-                        continue;
-                    }
-
-                    if !std::fs::metadata(&path).expect("Unable to read path metadata.").is_dir() {
-                        eprintln!("\"{path}\" does not appear to be a directory. Please try again...");
-                        continue;
-                    }
-
-                    std::fs::read_dir(path).expect("Unable to read path, even after confirming its existence.")
-                };
-                
-                let config = build_config_from_dir(dir);
-            
-                write_config(&config, file).unwrap();
-                
-                return config;
-            }
-        }
-
-        // find local classes/weeks 
-        let response = prompt!("Would you like to create a new config and add classes now? [Y/n]: ");
-
-        if response.len() == 0 || response.to_ascii_lowercase() == "y" {
-            let config = init_classes();
+    if content.is_empty() {
+        let default_editor = std::env::var("EDITOR").ok().filter(|e| !e.is_empty());
+        let Some(config) = setup_wizard(&mut Stdin, default_editor) else {
+            return Config::default();
+        };
 
-            write_config(&config, file).unwrap();
-                
-            return config;
-        }
+        write_config(&config, file).unwrap();
 
-        return Config::default();
+        return config;
     }
 
     return toml::from_str(&content).expect("Failed to parse TOML.");
@@ -265,34 +270,56 @@ fn get_config_path() -> PathBuf {
     ;
 }
 
+/// Where the config is kept: `path` if given, otherwise `~/.config/classnote/config.toml`.
+pub fn config_file_path(path: Option<&PathBuf>) -> PathBuf {
+    match path {
+        Some(p) => p.clone(),
+        None => get_config_path().join("config.toml")
+    }
+}
+
 pub fn get_config_file(path: Option<&PathBuf>) -> File {
-    let file_path = match path {
-        Some(p) => p,
-        None => {
-            let config_path = get_config_path();
-            &config_path.join("config.toml")
-        }
-    };
+    let file_path = config_file_path(path);
 
     std::fs::create_dir_all(file_path.parent().unwrap()).expect("Unable to create config directory.");
     std::fs::File::options().append(true).read(true).create(true).open(file_path).expect("Unable to create config file. Please check home directory .config permissions")
 }
 
-fn get_time(which: &str, day: Day, previous: Option<Time>) -> Time {
-    let mut time = Time::default();
-    time.day = day;
+/// Reads the config file without prompting, or `None` if there's no config yet.
+pub fn load_config(path: Option<&PathBuf>) -> Result<Option<Config>, String> {
+    let file_path = config_file_path(path);
+    let content = match std::fs::read_to_string(&file_path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("Unable to read \"{}\": {e:#}", file_path.to_string_lossy())),
+    };
+
+    if content.trim().is_empty() {
+        return Ok(None);
+    }
+
+    return toml::from_str(&content).map(Some).map_err(|e| format!("Unable to parse \"{}\": {e}", file_path.to_string_lossy()));
+}
+
+/// Overwrites the config file with `config`.
+pub fn save_config(config: &Config, path: Option<&PathBuf>) -> std::io::Result<()> {
+    std::fs::write(config_file_path(path), config.serialize())
+}
+
+fn get_time(p: &mut dyn Prompter, which: &str, day: Day, previous: Option<Time>) -> Time {
+    let mut time = Time { day, ..Default::default() };
 
     loop {
-       let input = prompt!("What time on {day} does this class {which} (13:00, 1:00 PM): ");
+        let input = p.ask(&format!("What time on {day} does this class {which} (13:00, 1:00 PM): "));
 
-        if input.len() == 0 {
-            println!("Please enter a valid {which} time.");
+        if input.is_empty() {
+            p.say(&format!("Please enter a valid {which} time."));
             continue;
         }
 
         (time.hour, time.minute, time.second) = match parse_time(&input) {
             Err(e) => {
-                println!("{e}");
+                p.say(e);
                 continue;
             },
             Ok(t) => t
@@ -300,8 +327,7 @@ fn get_time(which: &str, day: Day, previous: Option<Time>) -> Time {
 
         if let Some(start_time) = previous {
             time.day = if time.hour < start_time.hour || (time.hour == start_time.hour && time.minute < start_time.minute) {
-                let input = prompt!("Confirm class spans between two days [y/N]: ");
-                if input.len() == 0 || input.trim().to_ascii_lowercase().starts_with('n') {
+                if !p.confirm("Confirm class spans between two days [y/N]: ", false) {
                     continue;
                 }
                 
@@ -311,53 +337,90 @@ fn get_time(which: &str, day: Day, previous: Option<Time>) -> Time {
             };
         }
 
-        println!("Set class to {which} at {time}");
+        p.say(&format!("Set class to {which} at {time}"));
         return time;
     }
 }
 
-fn get_location(of: &str, day: Day) -> Location {
-    println!("Please fill out location information for {of} on {day}...");
+fn get_location(p: &mut dyn Prompter, of: &str, day: Day) -> Location {
+    p.say(&format!("Please fill out location information for {of} on {day}..."));
     // what campus
     Location { 
-        campus: demand_stdin("Campus"),
-        building: demand_stdin("Building name/number"),
-        room: demand_stdin("Room name/number")
+        campus: p.demand("Campus"),
+        building: p.demand("Building name/number"),
+        room: p.demand("Room name/number")
     }
 }
 
-pub fn init_classes() -> Config {
+/// Asks where to keep notes, which classes to take them for and which editor to use.
+pub fn init_classes(p: &mut dyn Prompter, default_editor: Option<String>) -> Config {
     let mut config = Config::default();
     
     loop {
-        let input = prompt!(required, "Where would you like to store notes? (Will be created if it doesn't exist): ");
+        let input = p.required("Where would you like to store notes? (Will be created if it doesn't exist): ");
         let result = match std::fs::exists(&input) {
-            Ok(exists) if exists => Ok(config.set_root(PathBuf::from(&input))),
-            Ok(_) => create_dir_all(&input),
+            Ok(true) => Ok(()),
+            Ok(false) => create_dir_all(&input),
             Err(e) => {
-                eprintln!("Unable to check for file existence. Please try again, or press Ctrl+c to exit the program. ({e:#})");
+                p.warn(&format!("Unable to check for file existence. Please try again, or press Ctrl+c to exit the program. ({e:#})"));
                 continue;
             }
         };
 
         match result {
-            Ok(_) => break,
-            Err(e) => eprintln!("Failed to create path \"{input}\", please try again: {e:#}")
+            Ok(_) => {
+                config.set_root(PathBuf::from(&input));
+                break;
+            },
+            Err(e) => p.warn(&format!("Failed to create path \"{input}\", please try again: {e:#}"))
         }
     }
     
 
     loop {
         // create enum for items/
-        let name = demand_stdin("Class name");
-        config.add_class(get_class(name));
+        let name = p.demand("Class name");
+        config.add_class(get_class(p, name));
 
-        let response = prompt!("Would you like to add another class? [Y/n]: ");
-        match response.as_str() {
-            "" | "Y" | "y" => continue,
-            _ => break
+        if !p.confirm("Would you like to add another class? [Y/n]: ", true) {
+            break;
         }
     }
 
+    let editor = get_editor(p, &config.get_root(), default_editor);
+    config.set_editor(editor);
+
     return config;
-}
\ No newline at end of file
+}
+
+/// Notices edits to the config file, for commands that keep running
+pub struct ConfigWatcher {
+    path: Option<PathBuf>,
+    modified: Option<SystemTime>,
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+impl ConfigWatcher {
+    pub fn new(path: Option<&PathBuf>) -> ConfigWatcher {
+        ConfigWatcher { path: path.cloned(), modified: modified(&config_file_path(path)) }
+    }
+
+    /// The config read again if the file was modified since the last call, or `None` if it wasn't. Errors reading it
+    /// are reported, and also give `None`, so the caller carries on with the config it has.
+    pub fn reload(&mut self) -> Option<Config> {
+        let changed = modified(&config_file_path(self.path.as_ref()));
+        if changed == self.modified { return None; }
+        self.modified = changed;
+
+        match load_config(self.path.as_ref()) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("{e}");
+                None
+            }
+        }
+    }
+}
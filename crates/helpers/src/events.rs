@@ -0,0 +1,57 @@
+use chrono::{Local, NaiveDate, NaiveDateTime};
+use data::{Config, class::Class, event::Event};
+
+/// How far ahead new notes count down to events
+pub const COUNTDOWN_DAYS: i64 = 30;
+
+/// Parses an event date, `YYYY-MM-DD` or `YYYY-MM-DD HH:MM`.
+pub fn parse_event(title: String, date: &str) -> Result<Event, String> {
+    let date = date.trim();
+
+    if let Ok(at) = NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M") {
+        return Ok(Event { title, date: at.date(), time: Some(at.time()) });
+    }
+
+    return NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|date| Event { title, date, time: None })
+        .map_err(|_| format!("\"{date}\" isn't a date. Use YYYY-MM-DD or \"YYYY-MM-DD HH:MM\"."));
+}
+
+/// Events of every class, or only `course`, from `from` on and within `days` of it if given. Soonest first.
+pub fn upcoming_events<'a>(config: &'a Config, course: Option<&str>, from: NaiveDate, days: Option<i64>) -> Vec<(&'a Class, &'a Event)> {
+    let mut events = config.get_classes()
+        .iter()
+        .filter(|class| course.is_none_or(|c| class.get_name().eq_ignore_ascii_case(c)))
+        .flat_map(|class| class.get_events().iter().map(move |event| (class, event)))
+        .filter(|(_, event)| event.days_until(from) >= 0 && days.is_none_or(|days| event.days_until(from) <= days))
+        .collect::<Vec<_>>();
+
+    events.sort_by_key(|(_, event)| (event.date, event.time));
+
+    return events;
+}
+
+pub fn print_upcoming(config: &Config, course: Option<&str>, days: Option<i64>) {
+    let today = Local::now().date_naive();
+    let events = upcoming_events(config, course, today, days);
+
+    if events.is_empty() {
+        println!("Nothing coming up.");
+        return;
+    }
+
+    for (class, event) in events {
+        println!("{}: {event} ({})", class.get_name(), event.relative(today));
+    }
+}
+
+/// A line counting down to the class's events in the next [`COUNTDOWN_DAYS`], like "Midterm in 9 days, Project demo in 12 days"
+pub fn countdowns(class: &Class, today: NaiveDate) -> Option<String> {
+    let upcoming = class.get_events()
+        .iter()
+        .filter(|e| (0..=COUNTDOWN_DAYS).contains(&e.days_until(today)))
+        .map(|e| e.countdown(today))
+        .collect::<Vec<_>>();
+
+    return (!upcoming.is_empty()).then(|| upcoming.join(", "));
+}
@@ -0,0 +1,57 @@
+//! Minimal handling of the YAML frontmatter Obsidian reads note properties from.
+
+/// Splits a note into its frontmatter (without the `---` fences) and the rest of the note.
+pub fn split(content: &str) -> (Option<&str>, &str) {
+    let Some(rest) = content.strip_prefix("---\n") else { return (None, content); };
+
+    if let Some(body) = rest.strip_prefix("---\n") {
+        return (Some(""), body);
+    }
+
+    match rest.find("\n---\n") {
+        Some(end) => (Some(&rest[..end]), &rest[end + 5..]),
+        None => match rest.strip_suffix("\n---") {
+            Some(frontmatter) => (Some(frontmatter), ""),
+            None => (None, content),
+        }
+    }
+}
+
+/// The note without its frontmatter
+pub fn strip(content: &str) -> &str {
+    split(content).1
+}
+
+/// Value of a top-level `key: value` property, if set.
+pub fn get_field<'a>(content: &'a str, key: &str) -> Option<&'a str> {
+    split(content).0?
+        .lines()
+        .find_map(|line| line.strip_prefix(key)?.strip_prefix(':'))
+        .map(str::trim)
+}
+
+/// Sets a top-level `key: value` property, adding frontmatter to the note if it has none.
+pub fn set_field(content: &str, key: &str, value: &str) -> String {
+    let (frontmatter, body) = split(content);
+
+    let mut lines = frontmatter.unwrap_or_default().lines().map(String::from).collect::<Vec<_>>();
+    let line = format!("{key}: {value}");
+    match lines.iter_mut().find(|l| l.strip_prefix(key).is_some_and(|rest| rest.starts_with(':'))) {
+        Some(existing) => *existing = line,
+        None => lines.push(line),
+    }
+
+    return format!("---\n{}\n---\n{body}", lines.join("\n"));
+}
+
+/// Removes a top-level property, leaving the note as it is if it isn't set.
+pub fn remove_field(content: &str, key: &str) -> String {
+    let (Some(frontmatter), body) = split(content) else { return content.to_string(); };
+
+    let lines = frontmatter.lines().filter(|l| !l.strip_prefix(key).is_some_and(|rest| rest.starts_with(':'))).collect::<Vec<_>>();
+    if lines.len() == frontmatter.lines().count() {
+        return content.to_string();
+    }
+
+    return if lines.is_empty() { format!("---\n---\n{body}") } else { format!("---\n{}\n---\n{body}", lines.join("\n")) };
+}
@@ -0,0 +1,120 @@
+use std::{path::Path, process::{Command, Output}};
+
+use data::git::Git;
+
+/// How a sync after an editing session ended
+#[derive(Debug, PartialEq)]
+pub enum SyncOutcome {
+    /// Nothing had changed since the last commit
+    Unchanged,
+    Committed,
+    /// Committed, then pulled and/or pushed as configured
+    Synced,
+    /// Committed locally, but pulling conflicted with these files. The merge was aborted, so the local commit is untouched.
+    Conflict(Vec<String>),
+    /// Committed locally, but pulling or pushing failed with this error
+    Unsynced(String),
+}
+
+fn git(root: &Path, args: &[&str]) -> Result<Output, String> {
+    Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Unable to run git: {e:#}"))
+}
+
+// runs git, turning a failed exit status into an error carrying its stderr
+fn git_ok(root: &Path, args: &[&str]) -> Result<Output, String> {
+    let output = git(root, args)?;
+    if !output.status.success() {
+        return Err(format!("`git {}` failed: {}", args.join(" "), String::from_utf8_lossy(&output.stderr).trim()));
+    }
+
+    return Ok(output);
+}
+
+/// Makes `root` a git repository on the configured branch, with `origin` pointing at the configured remote.
+pub fn init_repo(root: &Path, config: &Git) -> Result<(), String> {
+    if !root.join(".git").exists() {
+        git_ok(root, &["init", "--quiet", "--initial-branch", &config.branch])?;
+    }
+
+    if let Some(remote) = &config.remote {
+        match git(root, &["remote", "get-url", "origin"])? {
+            output if !output.status.success() => { git_ok(root, &["remote", "add", "origin", remote])?; },
+            output if String::from_utf8_lossy(&output.stdout).trim() != remote => { git_ok(root, &["remote", "set-url", "origin", remote])?; },
+            _ => {}
+        }
+    }
+
+    return Ok(());
+}
+
+fn remote_has_branch(root: &Path, branch: &str) -> Result<bool, String> {
+    let output = git_ok(root, &["ls-remote", "--heads", "origin", branch])?;
+    return Ok(!output.stdout.is_empty());
+}
+
+/// Commits everything under `dir`, and nothing else the user may have staged, with `message`, then pulls and
+/// pushes as configured. `Err` only if nothing was committed.
+pub fn commit_and_sync(root: &Path, dir: &Path, message: &str, config: &Git) -> Result<SyncOutcome, String> {
+    init_repo(root, config)?;
+
+    let dir = dir.strip_prefix(root).unwrap_or(dir).to_string_lossy().to_string();
+    let pathspec = if dir.is_empty() { "." } else { &dir };
+    git_ok(root, &["add", "--all", "--", pathspec])?;
+
+    // nothing staged under dir
+    if git(root, &["diff", "--cached", "--quiet", "--", pathspec])?.status.success() {
+        return Ok(SyncOutcome::Unchanged);
+    }
+
+    git_ok(root, &["commit", "--quiet", "--message", message, "--", pathspec])?;
+
+    if config.remote.is_none() || !(config.pull || config.push) {
+        return Ok(SyncOutcome::Committed);
+    }
+
+    return Ok(sync(root, config).unwrap_or_else(SyncOutcome::Unsynced));
+}
+
+fn sync(root: &Path, config: &Git) -> Result<SyncOutcome, String> {
+    if config.pull && remote_has_branch(root, &config.branch)? {
+        let pull = git(root, &["pull", "--quiet", "--no-rebase", "--no-edit", "origin", &config.branch])?;
+        if !pull.status.success() {
+            let conflicts = git_ok(root, &["diff", "--name-only", "--diff-filter=U"])?;
+            let conflicts = String::from_utf8_lossy(&conflicts.stdout).lines().map(String::from).collect::<Vec<_>>();
+
+            if conflicts.is_empty() {
+                return Err(format!("Unable to pull from origin: {}", String::from_utf8_lossy(&pull.stderr).trim()));
+            }
+
+            git_ok(root, &["merge", "--abort"])?;
+            return Ok(SyncOutcome::Conflict(conflicts));
+        }
+    }
+
+    if config.push {
+        git_ok(root, &["push", "--quiet", "--set-upstream", "origin", &format!("HEAD:{}", config.branch)])?;
+    }
+
+    return Ok(SyncOutcome::Synced);
+}
+
+/// Tells the user how a sync went.
+pub fn report_sync(root: &Path, message: &str, result: Result<SyncOutcome, String>) {
+    match result {
+        Ok(SyncOutcome::Unchanged) => println!("No changes to commit."),
+        Ok(SyncOutcome::Committed) => println!("Committed \"{message}\"."),
+        Ok(SyncOutcome::Synced) => println!("Committed and synced \"{message}\"."),
+        Ok(SyncOutcome::Unsynced(e)) => eprintln!("Committed \"{message}\" locally, but couldn't sync it: {e}"),
+        Ok(SyncOutcome::Conflict(files)) => eprintln!(
+            "Committed \"{message}\", but pulling conflicts with:\n\t{}\nYour edits are safe in the local commit. Resolve the conflict with git in \"{}\".",
+            files.join("\n\t"),
+            root.to_string_lossy()
+        ),
+        Err(e) => eprintln!("Unable to commit notes: {e}"),
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::io::{stdin, Error};
 
 pub fn stdin_readline() -> Result<String, Error> {
@@ -13,15 +14,117 @@ pub fn get_trimmed_stdin() -> String {
 }
 
 pub fn demand_stdin(thing: &str) -> String {
-    loop {
-        let input = prompt!("{thing}: ");
+    Stdin.demand(thing)
+}
+
+/// Where interactive setup gets its answers from, so it can run on a terminal or from a script.
+pub trait Prompter {
+    /// Asks `question` and returns the trimmed answer.
+    fn ask(&mut self, question: &str) -> String;
+
+    /// Tells the user something that needs no answer.
+    fn say(&mut self, message: &str);
+
+    /// Reports a problem with an answer.
+    fn warn(&mut self, message: &str) {
+        self.say(message);
+    }
+
+    /// Lets the user pick one of `items`, returning its index, or `None` if they cancel.
+    fn pick(&mut self, title: &str, items: &[String]) -> Option<usize>;
+
+    /// Asks until the answer isn't empty.
+    fn demand(&mut self, thing: &str) -> String {
+        loop {
+            let input = self.ask(&format!("{thing}: "));
+            if input.is_empty() {
+                self.say(&format!("Please enter {thing}."));
+                continue;
+            }
+
+            return input;
+        }
+    }
+
+    /// Like `prompt!(required, ...)`.
+    fn required(&mut self, question: &str) -> String {
+        loop {
+            let input = self.ask(question);
+            if input.is_empty() {
+                self.say("This is a required field.");
+                continue;
+            }
 
-        if input.len() == 0 {
-            println!("Please enter {thing}.");
-            continue;
+            return input;
         }
+    }
+
+    /// Asks a yes/no question, taking an empty answer as `default`.
+    fn confirm(&mut self, question: &str, default: bool) -> bool {
+        let input = self.ask(question);
+        if input.is_empty() { return default; }
+
+        return input.to_ascii_lowercase().starts_with('y');
+    }
+}
+
+/// Prompts on the terminal
+pub struct Stdin;
+
+impl Prompter for Stdin {
+    fn ask(&mut self, question: &str) -> String {
+        prompt!("{question}")
+    }
+
+    fn say(&mut self, message: &str) {
+        println!("{message}");
+    }
+
+    fn warn(&mut self, message: &str) {
+        eprintln!("{message}");
+    }
+
+    fn pick(&mut self, title: &str, items: &[String]) -> Option<usize> {
+        super::picker::pick(title, items)
+    }
+}
+
+/// Answers questions from a fixed list, recording everything asked and said, for tests.
+#[derive(Debug, Default)]
+pub struct Scripted {
+    answers: VecDeque<String>,
+    pub transcript: Vec<String>,
+}
+
+impl Scripted {
+    pub fn new<S: Into<String>>(answers: impl IntoIterator<Item = S>) -> Scripted {
+        Scripted { answers: answers.into_iter().map(Into::into).collect(), transcript: Vec::new() }
+    }
+
+    /// Answers not asked for yet
+    pub fn remaining(&self) -> usize {
+        self.answers.len()
+    }
+}
+
+impl Prompter for Scripted {
+    fn ask(&mut self, question: &str) -> String {
+        self.transcript.push(question.to_string());
+
+        match self.answers.pop_front() {
+            Some(answer) => answer.trim().to_string(),
+            None => panic!("Ran out of scripted answers at \"{question}\"."),
+        }
+    }
+
+    fn say(&mut self, message: &str) {
+        self.transcript.push(message.to_string());
+    }
 
-        return input.to_string();
+    /// Takes a 1-based number, or an empty answer to cancel
+    fn pick(&mut self, title: &str, items: &[String]) -> Option<usize> {
+        let input = self.ask(&format!("{title} [1-{}]", items.len()));
+        input.parse::<usize>().ok().filter(|n| (1..=items.len()).contains(n)).map(|n| n - 1)
     }
 }
 
@@ -1,6 +1,26 @@
+pub mod assignments;
+pub mod attach;
+pub mod attendance;
 pub mod config;
+pub mod events;
+pub mod frontmatter;
+pub mod git;
 pub mod io;
+pub mod mark;
+pub mod moc;
 pub mod note;
+pub mod now;
+pub mod output;
+pub mod picker;
+pub mod questions;
+pub mod recording;
+pub mod reminders;
+pub mod review;
+pub mod search;
+pub mod session;
+pub mod stats;
+pub mod status;
+pub mod tick;
 
 mod parse_time;
 pub use parse_time::parse_time;
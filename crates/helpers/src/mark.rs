@@ -0,0 +1,41 @@
+use std::{io::Write, path::PathBuf};
+
+use chrono::Local;
+use data::{Config, time::Time};
+
+use super::note::get_current_session_note;
+use super::recording::{RecordingLog, recording_link};
+
+// "HH:MM" since the start of class, negative during the early grace period
+fn since_start(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let minutes = seconds.abs() / 60;
+    return format!("{sign}{:0>2}:{:0>2}", minutes / 60, minutes % 60);
+}
+
+/// Appends a `[HH:MM] text` bookmark to the note of the class in session, returning the note's path.
+///
+/// While the session is being recorded, the timestamp counts from the start of class and links to the moment in the recording.
+pub fn add_mark(config: &Config, text: &str) -> Result<PathBuf, String> {
+    let now = Local::now();
+    let curr_time = Time::now();
+
+    let (path, mut note, class_time) = get_current_session_note(config)?;
+    let session_dir = path.parent().unwrap_or(&path);
+
+    let mark = match RecordingLog::read(session_dir).at(now) {
+        Some(recording) => format!(
+            "{} {text}",
+            recording_link(&since_start(curr_time.seconds_since(&class_time.start)), &recording.file, (now - recording.started).num_seconds())
+        ),
+        None => format!("[{}] {text}", now.format("%H:%M")),
+    };
+
+    // keep the mark on its own line, even if the note doesn't end with one
+    let content = std::fs::read_to_string(&path).unwrap_or_default();
+    let separator = if content.is_empty() || content.ends_with('\n') { "" } else { "\n" };
+
+    note.write_all(format!("{separator}{mark}\n").as_bytes()).map_err(|e| format!("Unable to write to \"{}\": {e:#}", path.to_string_lossy()))?;
+
+    return Ok(path);
+}
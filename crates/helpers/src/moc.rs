@@ -0,0 +1,159 @@
+use std::path::{Path, PathBuf};
+
+use data::{Config, obsidian::file_in_vault};
+
+use super::frontmatter::{remove_field, set_field};
+use super::session::{Session, get_sessions};
+
+const GENERATED: &str = "%% Generated by classnote. Changes to this note will be overwritten. %%";
+
+/// Map of Content listing every week of `course`, kept next to its week folders
+pub fn course_moc_path(root: &Path, course: &str) -> PathBuf {
+    root.join(course).join(format!("{course}.md"))
+}
+
+/// Map of Content listing every session of a week, kept in the week folder
+pub fn week_moc_path(root: &Path, course: &str, week: u16) -> PathBuf {
+    root.join(course).join(format!("Week-{week}")).join(format!("Week-{week}.md"))
+}
+
+// session and week notes share names across courses, so links spell out the path from the vault root
+struct Linker {
+    vault: PathBuf,
+}
+
+impl Linker {
+    fn link(&self, note: &Path, label: &str) -> String {
+        let target = file_in_vault(&self.vault, note).unwrap_or_else(|| note.to_string_lossy().to_string());
+        format!("[[{}|{label}]]", target.strip_suffix(".md").unwrap_or(&target))
+    }
+
+    // wikilinks in frontmatter have to be quoted to stay valid YAML
+    fn property(&self, note: &Path, label: &str) -> String {
+        format!("\"{}\"", self.link(note, label))
+    }
+}
+
+fn session_label(session: &Session) -> String {
+    format!("Class {} ({})", session.number, session.date)
+}
+
+fn write_if_changed(path: &Path, content: &str) -> std::io::Result<()> {
+    if std::fs::read_to_string(path).is_ok_and(|existing| existing == content) {
+        return Ok(());
+    }
+
+    std::fs::write(path, content)
+}
+
+fn week_moc(linker: &Linker, root: &Path, course: &str, week: u16, sessions: &[&Session]) -> String {
+    let mut moc = format!("---\ncourse: {}\ntags: [classnote/week]\n---\n{GENERATED}\n\n# {course} - Week {week}\n\n", linker.property(&course_moc_path(root, course), course));
+    for session in sessions {
+        moc += &format!("- {}\n", linker.link(&session.note, &session_label(session)));
+    }
+
+    return moc;
+}
+
+fn course_moc(linker: &Linker, root: &Path, course: &str, weeks: &[(u16, Vec<&Session>)], professor: Option<&str>) -> String {
+    let mut moc = format!("---\ntags: [classnote/course]\n---\n{GENERATED}\n\n# {course}\n\n");
+    if let Some(professor) = professor {
+        moc += &format!("Professor: {professor}\n\n");
+    }
+
+    for (week, sessions) in weeks {
+        moc += &format!("## {}\n\n", linker.link(&week_moc_path(root, course, *week), &format!("Week {week}")));
+        for session in sessions {
+            moc += &format!("- {}\n", linker.link(&session.note, &session_label(session)));
+        }
+        moc += "\n";
+    }
+
+    return moc;
+}
+
+// gives the note its course, week and neighbouring sessions as properties, leaving the rest of the note alone
+fn link_session_note(linker: &Linker, root: &Path, session: &Session, previous: Option<&Session>, next: Option<&Session>) -> std::io::Result<()> {
+    let Ok(content) = std::fs::read_to_string(&session.note) else { return Ok(()); };
+
+    let mut linked = set_field(&content, "date", &session.date.to_string());
+    linked = set_field(&linked, "course", &linker.property(&course_moc_path(root, &session.course), &session.course));
+    linked = set_field(&linked, "week", &linker.property(&week_moc_path(root, &session.course, session.week), &format!("Week {}", session.week)));
+
+    for (key, neighbour) in [("previous", previous), ("next", next)] {
+        match neighbour {
+            Some(neighbour) => linked = set_field(&linked, key, &linker.property(&neighbour.note, &session_label(neighbour))),
+            // a neighbour that's gone shouldn't leave a dangling link behind
+            None => linked = remove_field(&linked, key),
+        }
+    }
+
+    write_if_changed(&session.note, &linked)
+}
+
+// sessions of `course` that have a note, and the linker, when notes are opened in Obsidian
+fn linked_sessions(config: &Config, course: &str) -> Option<(Linker, Vec<Session>)> {
+    let uri = config.get_editor().obsidian()?;
+
+    let root = config.get_root();
+    let linker = Linker { vault: uri.resolve_vault_path().unwrap_or(root.clone()) };
+    let sessions = get_sessions(&root, course).into_iter().filter(|s| s.note.is_file()).collect::<Vec<_>>();
+
+    return (!sessions.is_empty()).then_some((linker, sessions));
+}
+
+fn link_nth(linker: &Linker, root: &Path, sessions: &[Session], i: usize) -> std::io::Result<()> {
+    link_session_note(linker, root, &sessions[i], i.checked_sub(1).map(|i| &sessions[i]), sessions.get(i + 1))
+}
+
+fn write_mocs(config: &Config, linker: &Linker, course: &str, sessions: &[Session]) -> std::io::Result<()> {
+    let root = config.get_root();
+
+    let mut weeks: Vec<(u16, Vec<&Session>)> = Vec::new();
+    for session in sessions {
+        match weeks.iter_mut().find(|(week, _)| *week == session.week) {
+            Some((_, week)) => week.push(session),
+            None => weeks.push((session.week, vec![session])),
+        }
+    }
+    weeks.sort_by_key(|(week, _)| *week);
+
+    for (week, week_sessions) in &weeks {
+        write_if_changed(&week_moc_path(&root, course, *week), &week_moc(linker, &root, course, *week, week_sessions))?;
+    }
+
+    let professor = config.get_classes().iter().find(|c| c.get_name() == course).map(|c| c.get_professor());
+    write_if_changed(&course_moc_path(&root, course), &course_moc(linker, &root, course, &weeks, professor.map(String::as_str)))
+}
+
+/// Regenerates the course and week Maps of Content of `course` and links each session note to its neighbours.
+/// Only does anything when notes are opened in Obsidian.
+pub fn link_course(config: &Config, course: &str) -> std::io::Result<()> {
+    let Some((linker, sessions)) = linked_sessions(config, course) else { return Ok(()); };
+
+    for i in 0..sessions.len() {
+        link_nth(&linker, &config.get_root(), &sessions, i)?;
+    }
+
+    write_mocs(config, &linker, course, &sessions)
+}
+
+/// Links a newly created session note into its course, reporting any failure. Only the new note and the sessions
+/// on either side of it are rewritten, along with the Maps of Content.
+pub fn link_new_session(config: &Config, note: &Path) {
+    let root = config.get_root();
+    let Some(session) = Session::from_note(&root, note) else { return; };
+    let Some((linker, sessions)) = linked_sessions(config, &session.course) else { return; };
+    let Some(at) = sessions.iter().position(|s| s.dir == session.dir) else { return; };
+
+    let linked = [at.checked_sub(1), Some(at), Some(at + 1)]
+        .into_iter()
+        .flatten()
+        .filter(|&i| i < sessions.len())
+        .try_for_each(|i| link_nth(&linker, &root, &sessions, i))
+        .and_then(|_| write_mocs(config, &linker, &session.course, &sessions));
+
+    if let Err(e) = linked {
+        eprintln!("Unable to update links of {}: {e:#}", session.course);
+    }
+}
@@ -1,7 +1,17 @@
-use std::{fs::{DirEntry, File}, path::PathBuf, process::Command, time};
+use std::{fs::{DirEntry, File}, io::Write, path::{Path, PathBuf}, process::{Child, Command}};
 
-use chrono::{DateTime, Local};
-use data::{Config, Editor, class::Class, obsidian::ObsidianPath, time::{Time, Times}};
+use chrono::{Local, NaiveDate, NaiveTime};
+use data::{Config, class::Class, editor::EditorBackend, time::{ClassTime, Time, Times}};
+
+use super::assignments::print_due_soon;
+use super::events::countdowns;
+use super::io::prompt;
+use super::picker::{pick, pick_class};
+use super::recording::{ActiveRecording, start_recording};
+use super::git::{commit_and_sync, report_sync};
+use super::frontmatter;
+use super::moc::link_new_session;
+use super::session::{Session, get_sessions};
 
 
 pub fn create_note(name: &str) -> File{
@@ -10,13 +20,13 @@ pub fn create_note(name: &str) -> File{
 
 // using u16 in case the class is realllly long (1200 years)
 pub fn get_latest_week_num(config: &Config, class: &Class) -> u16 {
-    let root = config.get_root();
-    if !std::fs::exists(&root).expect("Unable to check root's existence.") { return 0; }
+    // weeks start at 1, whether or not the root or course folder exist yet
+    let class_path = config.get_root().join(class.get_name());
+    if !class_path.exists() { return 1; }
 
-    let class_path = root.join(class.get_name());
-    if !std::fs::exists(&class_path).expect("Unable to check class's path existence.") { return 1; }
-    
-    std::fs::read_dir(class_path).expect("The class path is either inaccessible or not a directory.")
+    std::fs::read_dir(class_path)
+        .into_iter()
+        .flatten()
         .filter_map(Result::ok)
         // get directories
         .filter_map(|e| 
@@ -37,22 +47,41 @@ pub fn get_latest_week_num(config: &Config, class: &Class) -> u16 {
         .unwrap_or(1)
 }
 
-pub fn get_current_classnote(config: &Config, class: &Class) -> (PathBuf, File) {
+pub fn get_current_classnote(config: &Config, class: &Class) -> std::io::Result<(PathBuf, File)> {
+    let (path, file, _) = get_or_create_classnote(config, class)?;
+    return Ok((path, file));
+}
+
+/// Like [`get_current_classnote`], also telling whether today's session note already existed.
+pub fn get_or_create_classnote(config: &Config, class: &Class) -> std::io::Result<(PathBuf, File, bool)> {
+    let (file_path, reused) = session_note_on(config, class, Local::now().date_naive())?;
+    let file = std::fs::OpenOptions::new().append(true).read(true).open(&file_path)?;
+
+    return Ok((file_path, file, reused));
+}
+
+/// How many session folders a week folder of `class` holds before new sessions start the next week
+pub fn sessions_per_week(class: &Class) -> usize {
+    class.get_times().len().max(1)
+}
+
+/// The note of `class`'s session on `date`, created along with its folders if needed. `true` if it already existed.
+pub fn session_note_on(config: &Config, class: &Class, date: NaiveDate) -> std::io::Result<(PathBuf, bool)> {
     let latest_week = get_latest_week_num(config, class);
     let course_path = config.get_root().join(class.get_name());
-    let week_entries = std::fs::read_dir(course_path.join(format!("Week-{latest_week}"))).unwrap().filter_map(Result::ok).collect::<Vec<DirEntry>>();
+    std::fs::create_dir_all(course_path.join(format!("Week-{latest_week}")))?;
+    // only session folders count towards the week, not files like the week's map of content
+    let week_entries = std::fs::read_dir(course_path.join(format!("Week-{latest_week}")))?
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_ok_and(|f| f.is_dir()))
+        .collect::<Vec<DirEntry>>();
 
     let last_class_num = week_entries
         .iter()
-        .filter_map(|e|
-            e.file_type().ok().and_then(|f|
-                f.is_dir().then_some(e)
-            )
-        )
         .filter_map(|f| {
             f.file_name().to_string_lossy()
                 .trim_end_matches('/')
-                .strip_prefix("Class")?
+                .strip_prefix("Class-")?
                 .split('-')
                 .next()?
                 .parse::<u16>()
@@ -62,71 +91,154 @@ pub fn get_current_classnote(config: &Config, class: &Class) -> (PathBuf, File)
         .unwrap_or(0)
     ;
     
-    let current_time: DateTime<Local> = time::SystemTime::now().into();
-    let current_iso_8601 = current_time.format("%Y-%m-%d");
+    let current_iso_8601 = date.format("%Y-%m-%d");
     
     let potential_class_name = format!("Class-{}-{}", last_class_num, current_iso_8601);
     let potential_class_path = course_path.join(format!("Week-{}", latest_week)).join(&potential_class_name);
-    if std::fs::exists(&potential_class_path).expect("Unable to check for note folder path existence.") {
+    if potential_class_path.exists() {
         let file_path = potential_class_path.join(potential_class_name + ".md");
-        return (file_path.clone(), std::fs::OpenOptions::new().append(true).create(true).read(true).write(true).open(file_path).unwrap());
+        std::fs::OpenOptions::new().append(true).create(true).open(&file_path)?;
+        return Ok((file_path, true));
     }
 
-    let class_instance = format!("Class-{}-{}", last_class_num + 1, current_iso_8601);
-    let class_path =
-        if week_entries.len() >= class.get_times().len() {
-            let new_path = course_path.join(format!("Week-{}", latest_week + 1));
-            if !std::fs::exists(&new_path).expect("Unable to check for note folder path existence.") {
-                std::fs::create_dir(&new_path).expect("Unable to create new week directory.");
-            }
+    let week = if week_entries.len() >= sessions_per_week(class) { latest_week + 1 } else { latest_week };
+    let file_path = write_session_note(config, class, week, last_class_num + 1, date, note_template)?;
 
-            new_path
-        } else {
-            course_path.join(format!("Week-{latest_week}"))
-        }
-        .join(&class_instance)
-    ;
+    return Ok((file_path, false));
+}
+
+/// Creates the folder and note of session `number` of `class` in week `week`, filling the note from `template`
+/// (given the class and session name) if it's new.
+pub fn write_session_note(
+    config: &Config,
+    class: &Class,
+    week: u16,
+    number: u16,
+    date: NaiveDate,
+    template: impl FnOnce(&Class, &str) -> String,
+) -> std::io::Result<PathBuf> {
+    let class_instance = format!("Class-{number}-{}", date.format("%Y-%m-%d"));
+    let class_path = config.get_root().join(class.get_name()).join(format!("Week-{week}")).join(&class_instance);
 
-    std::fs::create_dir_all(&class_path).expect("Failed to create class directory.");
+    std::fs::create_dir_all(&class_path)?;
 
-    let file_path = class_path.join(class_instance + ".md");
-    // file_path.add_extension("md");
+    let file_path = class_path.join(class_instance.clone() + ".md");
+
+    let mut file = std::fs::OpenOptions::new().append(true).create(true).read(true).open(&file_path)?;
+    if file.metadata().is_ok_and(|m| m.len() == 0) {
+        file.write_all(template(class, &class_instance).as_bytes())?;
+        link_new_session(config, &file_path);
+    }
 
-    return (file_path.clone(), std::fs::OpenOptions::new().append(true).create(true).read(true).write(true).open(file_path).unwrap());
+    return Ok(file_path);
 }
 
-pub fn open_note(config: Config) {
-    // determine class based on times from config.
-    let curr_time = Time::now();
-    let curr_class: Option<Class> = config.get_classes().iter().filter_map(|class|
+/// Starts the banner of upcoming events in a new note
+pub const UPCOMING: &str = "> **Upcoming:**";
+/// Headings every new note starts with
+pub const TEMPLATE_HEADINGS: [&str; 2] = ["## Notes", "## Review"];
+
+/// Initial contents of a new session note. The Review section is what `classnote review` collects.
+pub fn note_template(class: &Class, session_name: &str) -> String {
+    let upcoming = countdowns(class, Local::now().date_naive()).map(|line| format!("{UPCOMING} {line}\n\n")).unwrap_or_default();
+
+    return format!("# {} - {session_name}\n\n{upcoming}{}\n\n", class.get_name(), TEMPLATE_HEADINGS.join("\n\n"));
+}
+
+/// Classes in session at `time` (grace periods included), closest meeting first.
+pub fn get_current_classes(config: &Config, time: Time) -> Vec<(Class, ClassTime)> {
+    let mut matches = config.get_classes().iter().filter_map(|class| 
         match class.get_times() {
             Times::Async => None,
-            Times::At(times) => times.iter().any(|time| time.includes(curr_time)).then_some(class.clone())
+            Times::At(times) => times
+                .iter()
+                .filter(|t| t.includes_with_grace(time, config.grace_for(class)))
+                .min_by_key(|t| t.distance(time))
+                .map(|t| (class.clone(), t.clone()))
         }
-    ).next();
+    ).collect::<Vec<_>>();
 
-    // if there's no class at this time, say so (perhaps determine other functionality in the future)
-    let Some(class) = curr_class else {
-        eprintln!("No class found for {curr_time}");
+    matches.sort_by_key(|(_, t)| t.distance(time));
+
+    return matches;
+}
+
+fn choose_class(candidates: Vec<(Class, ClassTime)>) -> Option<(Class, ClassTime)> {
+    let items = candidates
+        .iter()
+        .map(|(class, time)| format!("{} ({} to {})", class.get_name(), time.start.hms_string(), time.end.hms_string()))
+        .collect::<Vec<_>>();
+
+    return pick("Multiple classes are in session. Which one do you mean?", &items).map(|i| candidates[i].clone());
+}
+
+/// Narrows classes from [`get_current_classes`] down to one, asking the user when several are equally close to `time`.
+fn select_class(candidates: Vec<(Class, ClassTime)>, time: Time) -> Option<(Class, ClassTime)> {
+    match candidates.as_slice() {
+        [] => None,
+        [only] => Some(only.clone()),
+        [first, (_, next), ..] if first.1.distance(time) < next.distance(time) => Some(first.clone()),
+        _ => {
+            let closest = candidates[0].1.distance(time);
+            choose_class(candidates.into_iter().filter(|(_, t)| t.distance(time) == closest).collect())
+        }
+    }
+}
+
+/// The class in session at `time`, asking the user to choose if several are. `Err` if there are none or none was chosen.
+pub fn get_current_class(config: &Config, time: Time) -> Result<(Class, ClassTime), String> {
+    let candidates = get_current_classes(config, time);
+    if candidates.is_empty() {
+        return Err(format!("No class found for {time}."));
+    }
+
+    return select_class(candidates, time).ok_or("No class chosen.".into());
+}
+
+/// Today's note of the class in session, created if needed, along with the meeting it's for.
+pub fn get_current_session_note(config: &Config) -> Result<(PathBuf, File, ClassTime), String> {
+    let (class, class_time) = get_current_class(config, Time::now())?;
+
+    let (path, file) = get_current_classnote(config, &class).map_err(|e| format!("Unable to create a note for {}: {e:#}", class.get_name()))?;
+    return Ok((path, file, class_time));
+}
+
+/// Path of the most recent session note of `class`, if any have been taken.
+pub fn get_latest_classnote(config: &Config, class: &Class) -> Option<PathBuf> {
+    get_sessions(&config.get_root(), class.get_name())
+        .into_iter()
+        .filter(|s| s.note.is_file())
+        .max_by_key(|s| (s.week, s.number, s.date))
+        .map(|s| s.note)
+}
+
+fn editor_command(backend: &dyn EditorBackend, path: &Path, line: Option<usize>) -> Option<Command> {
+    let args = backend.command(path, line);
+    let (program, args) = args.split_first()?;
+
+    let mut command = Command::new(program);
+    command.args(args);
+
+    return Some(command);
+}
+
+/// Starts the configured editor on `path` without waiting for it, for front ends that can't hand over the terminal.
+pub fn spawn_editor(config: &Config, path: &Path, line: Option<usize>) -> Result<Child, String> {
+    let mut command = editor_command(&*config.get_editor().backend(), path, line).ok_or("No editor is configured.")?;
+
+    return command.spawn().map_err(|e| format!("Unable to start {}: {e:#}", config.get_editor_program()));
+}
+
+/// Opens `path` in the configured editor, at `line` if given. If something has to happen once editing is done
+/// (`then_finish`), editors that return right away are waited on by asking the user to press Enter.
+pub fn launch_editor(config: &Config, path: &Path, line: Option<usize>, then_finish: bool) {
+    let backend = config.get_editor().backend();
+    let Some(mut command) = editor_command(&*backend, path, line) else {
+        eprintln!("No editor is configured.");
         return;
     };
 
-    let (path, _note) = get_current_classnote(&config, &class);
-    
-    // perhaps later implement your own editor?
-    let status = match config.get_editor() {
-        Editor::Simple(program) => 
-            Command::new(program)
-                .arg(path)
-                .status()
-        ,
-
-        Editor::Complex { program, uri } => 
-            Command::new(program)
-                .arg(ObsidianPath::build_uri(&uri.vault, path))
-                .status()
-            
-    }.expect("Failed to start editor.");
+    let status = command.status().expect("Failed to start editor.");
 
     println!("{} exited {}.",
         config.get_editor_program(),
@@ -136,4 +248,207 @@ pub fn open_note(config: Config) {
             "with an error."
         }
     );
+
+    // anything after editing (stopping a recording, committing) has to wait until the note is actually done with
+    if then_finish && status.success() && !backend.waits() {
+        prompt!("Press Enter when you're done editing...");
+    }
+}
+
+/// Inserts `line` as its own paragraph below the note's title, or at the top if it has none.
+fn insert_below_title(note: &Path, line: &str) -> std::io::Result<()> {
+    let content = std::fs::read_to_string(note)?;
+    let body = frontmatter::strip(&content);
+    let start = content.len() - body.len();
+    let at = start + if body.starts_with("# ") { body.find('\n').map_or(body.len(), |i| i + 1) } else { 0 };
+
+    std::fs::write(note, format!("{}\n{line}\n{}", &content[..at], &content[at..]))
+}
+
+fn start_note_recording(config: &Config, note: &Path) -> Option<ActiveRecording> {
+    let recorder = config.get_recording().cloned().unwrap_or_default();
+    let session_name = note.file_stem()?.to_string_lossy().to_string();
+
+    let recording = match start_recording(&recorder, note.parent()?, &session_name) {
+        Ok(recording) => recording,
+        Err(e) => {
+            eprintln!("Unable to record: {e}");
+            return None;
+        }
+    };
+
+    let link = format!("> Recording: [{file}](<{file}>), started at {}", recording.started.format("%H:%M:%S"), file = recording.file_name());
+    if let Err(e) = insert_below_title(note, &link) {
+        eprintln!("Unable to link recording in note: {e:#}");
+    }
+
+    return Some(recording);
+}
+
+// is `line` an ATX heading of `level` or higher (fewer #s)?
+fn is_heading(line: &str, level: usize) -> bool {
+    let hashes = line.chars().take_while(|&c| c == '#').count();
+    (1..=level).contains(&hashes) && line[hashes..].starts_with(' ')
+}
+
+// moves `at` up past blank lines, staying below `start`
+fn back_up(lines: &[&str], start: usize, mut at: usize) -> usize {
+    while at > start && lines[at - 1].trim().is_empty() {
+        at -= 1;
+    }
+
+    return at;
+}
+
+/// Where new content goes: right after the last line written in the Notes section, or in the note if it has none.
+/// Returns the first line of the section and that point, both 0-indexed.
+fn notes_insertion_point(lines: &[&str]) -> (usize, usize) {
+    let notes = lines.iter().position(|l| l.trim().eq_ignore_ascii_case("## Notes"));
+    let end = match notes {
+        Some(i) => lines.iter().skip(i + 1).position(|l| is_heading(l, 2)).map_or(lines.len(), |end| i + 1 + end),
+        None => lines.len(),
+    };
+
+    let start = notes.map_or(0, |i| i + 1);
+    return (start, back_up(lines, start, end));
+}
+
+fn write_lines(note: &Path, lines: &[&str], trailing_newline: bool) -> std::io::Result<()> {
+    std::fs::write(note, lines.join("\n") + if trailing_newline { "\n" } else { "" })
+}
+
+/// Adds `text` as its own paragraph at the end of the note's Notes section.
+pub fn append_to_notes(note: &Path, text: &str) -> std::io::Result<()> {
+    let content = std::fs::read_to_string(note)?;
+    let mut lines = content.lines().collect::<Vec<_>>();
+    let (_, at) = notes_insertion_point(&lines);
+
+    let mut inserted = vec![""];
+    inserted.extend(text.lines());
+    if lines.get(at).is_some_and(|l| !l.trim().is_empty()) {
+        inserted.push("");
+    }
+    lines.splice(at..at, inserted);
+
+    write_lines(note, &lines, true)
+}
+
+/// Finds where to start typing: the end of the Notes section, or the end of the note if it has none.
+/// With `heading`, a timestamped heading is inserted there first. Returns the 1-indexed line to open the editor at.
+fn prepare_cursor(note: &Path, heading: bool) -> std::io::Result<usize> {
+    let content = std::fs::read_to_string(note)?;
+    let mut lines = content.lines().collect::<Vec<_>>();
+
+    let (start, mut at) = notes_insertion_point(&lines);
+
+    if !heading {
+        return Ok(at + 1);
+    }
+
+    // replace an earlier heading nothing was written under, rather than stacking empty ones up
+    if at > start && lines[at - 1].strip_prefix("### ").is_some_and(|t| NaiveTime::parse_from_str(t, "%H:%M").is_ok()) {
+        lines.remove(at - 1);
+        at = back_up(&lines, start, at - 1);
+    }
+
+    let title = format!("### {}", Local::now().format("%H:%M"));
+    // the blank lines up to the next section become a line to type on and a separator
+    let blank_end = lines[at..].iter().position(|l| !l.trim().is_empty()).map_or(lines.len(), |i| at + i);
+    let mut inserted = vec!["", title.as_str(), ""];
+    if blank_end < lines.len() {
+        inserted.push("");
+    }
+    lines.splice(at..blank_end, inserted);
+
+    write_lines(note, &lines, content.ends_with('\n'))?;
+
+    return Ok(at + 3);
+}
+
+/// Opens `note` in the editor, recording the lecture meanwhile if asked to, and commits it once the editor exits.
+/// `resume` is for reopening a session already underway, which gets a timestamped heading to continue under.
+pub fn edit_note(config: &Config, note: &Path, record: bool, resume: bool) {
+    let recording = if record { start_note_recording(config, note) } else { None };
+
+    let line = match prepare_cursor(note, resume) {
+        Ok(line) => Some(line),
+        Err(e) => {
+            eprintln!("Unable to prepare \"{}\": {e:#}", note.to_string_lossy());
+            None
+        }
+    };
+
+    // a recording to stop or a commit to make can only happen once the note is closed
+    launch_editor(config, note, line, recording.is_some() || config.get_git().is_some());
+
+    if let Some(recording) = recording {
+        match recording.stop() {
+            Ok(path) => println!("Saved recording to \"{}\".", path.to_string_lossy()),
+            Err(e) => eprintln!("{e}")
+        }
+    }
+
+    sync_note(config, note);
+}
+
+
+/// Commits the course folder of `note` if git integration is enabled, reporting how it went.
+pub fn sync_note(config: &Config, note: &Path) {
+    let Some(git_config) = config.get_git() else { return; };
+
+    let root = config.get_root();
+    let Some(session) = Session::from_note(&root, note) else {
+        eprintln!("\"{}\" isn't a session note, so it won't be committed.", note.to_string_lossy());
+        return;
+    };
+
+    let message = format!("{}: {} (Class {})", session.course, session.date, session.number);
+    report_sync(&root, &message, commit_and_sync(&root, &root.join(&session.course), &message, git_config));
+}
+
+/// Opens the most recent note of `class`, starting a new one if there are none yet, recording it if `record` is set
+/// or the config always records.
+pub fn open_latest_note(config: &Config, class: &Class, record: bool) {
+    // picking up an existing note resumes it under a new heading, like reopening a session in progress
+    let (path, resume) = match get_latest_classnote(config, class) {
+        Some(path) => (path, true),
+        None => match get_current_classnote(config, class) {
+            Ok((path, _)) => (path, false),
+            Err(e) => {
+                eprintln!("Unable to create a note for {}: {e:#}", class.get_name());
+                return;
+            }
+        },
+    };
+
+    edit_note(config, &path, record || config.get_recording().is_some_and(|r| r.always), resume);
+}
+
+/// Opens the note of the class in session, recording it if `record` is set or the config always records.
+pub fn open_note(config: Config, record: bool) {
+    // determine class based on times from config.
+    let curr_time = Time::now();
+    let candidates = get_current_classes(&config, curr_time);
+
+    // if there's no class at this time, let the user choose one
+    if candidates.is_empty() {
+        eprintln!("No class found for {curr_time}");
+        if let Some(class) = pick_class(&config) {
+            open_latest_note(&config, &class, record);
+        }
+        return;
+    }
+
+    let Some((class, _)) = select_class(candidates, curr_time) else { return; };
+
+    print_due_soon(&config, class.get_name());
+
+    let (path, _note, reused) = match get_or_create_classnote(&config, &class) {
+        Ok(note) => note,
+        Err(e) => {
+            eprintln!("Unable to create a note for {}: {e:#}", class.get_name());
+            return;
+        }
+    };
+    edit_note(&config, &path, record || config.get_recording().is_some_and(|r| r.always), reused);
 }
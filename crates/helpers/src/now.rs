@@ -0,0 +1,135 @@
+use data::{Config, class::Class, time::{ClassTime, Time, Times}};
+use serde::Serialize;
+
+use super::assignments::print_due_soon;
+use super::note::get_current_classes;
+
+/// A meeting of a class, relative to when it was asked about
+#[derive(Debug, Clone, Serialize)]
+pub struct Meeting {
+    pub class: String,
+    pub professor: String,
+    pub meeting: ClassTime,
+    /// Seconds until the meeting starts, negative once it has
+    pub starts_in: i64,
+    /// Seconds until the meeting ends, negative once it has
+    pub ends_in: i64,
+}
+
+impl Meeting {
+    fn new(class: &Class, meeting: ClassTime, time: Time) -> Meeting {
+        Meeting {
+            class: class.get_name().clone(),
+            professor: class.get_professor().clone(),
+            starts_in: meeting.start.seconds_since(&time),
+            ends_in: meeting.end.seconds_since(&time),
+            meeting,
+        }
+    }
+}
+
+/// `now` output
+#[derive(Debug, Serialize)]
+pub struct Now {
+    pub time: Time,
+    pub classes: Vec<Meeting>,
+}
+
+/// `next` output
+#[derive(Debug, Serialize)]
+pub struct Next {
+    pub time: Time,
+    pub next: Option<Meeting>,
+}
+
+/// Rough length of `seconds`, like "2d 3h" or "45m"
+pub fn short_duration(seconds: u32) -> String {
+    let (days, hours, minutes) = (seconds / 86400, seconds / 3600 % 24, (seconds / 60 % 60).max(1));
+    match (days, hours) {
+        (0, 0) => format!("{minutes}m"),
+        (0, _) => format!("{hours}h {minutes}m"),
+        _ => format!("{days}d {hours}h"),
+    }
+}
+
+/// Classes in session at `time`, closest meeting first.
+pub fn now(config: &Config, time: Time) -> Now {
+    let classes = get_current_classes(config, time).into_iter().map(|(class, meeting)| Meeting::new(&class, meeting, time)).collect();
+
+    return Now { time, classes };
+}
+
+/// The next meeting to start after `time`, wrapping around to next week.
+pub fn next_class(config: &Config, time: Time) -> Option<(Class, ClassTime)> {
+    config.get_classes()
+        .iter()
+        .flat_map(|class| match class.get_times() {
+            Times::Async => Vec::new(),
+            Times::At(times) => times.iter().map(|t| (class, t)).collect(),
+        })
+        .filter(|(_, t)| time.seconds_until(&t.start) > 0)
+        .min_by_key(|(_, t)| time.seconds_until(&t.start))
+        .map(|(class, t)| (class.clone(), t.clone()))
+}
+
+/// Like [`next_class`], counting the time until it starts forwards even when that's most of a week.
+pub fn next(config: &Config, time: Time) -> Next {
+    let next = next_class(config, time).map(|(class, meeting)| {
+        let mut next = Meeting::new(&class, meeting, time);
+        next.starts_in = time.seconds_until(&next.meeting.start) as i64;
+        next.ends_in = next.starts_in + next.meeting.start.seconds_until(&next.meeting.end) as i64;
+        next
+    });
+
+    return Next { time, next };
+}
+
+/// Prints the classes in session, where they meet and how long is left, along with what's due soon for each.
+pub fn print_now(config: &Config, now: &Now) {
+    if now.classes.is_empty() {
+        println!("No class in session at {}.", now.time);
+        return;
+    }
+
+    for class in &now.classes {
+        let time = &class.meeting;
+        // grace periods can put a class in session before it starts or after it ends
+        let status = if class.starts_in <= 0 && class.ends_in > 0 {
+            format!("until {} ({} minutes left)", time.end.hms_string(), class.ends_in / 60)
+        } else if class.starts_in > 0 {
+            format!("starting at {} (in {} minutes)", time.start.hms_string(), (class.starts_in as u32).div_ceil(60))
+        } else {
+            format!("ended at {}", time.end.hms_string())
+        };
+
+        println!("{} ({}) {status}, at {}, {} (Room {})",
+            class.class,
+            class.professor,
+            time.location.campus,
+            time.location.building,
+            time.location.room
+        );
+
+        print_due_soon(config, &class.class);
+    }
+}
+
+/// Prints when and where the next class meets.
+pub fn print_next(next: &Next) {
+    let Some(class) = &next.next else {
+        println!("No scheduled classes.");
+        return;
+    };
+
+    let time = &class.meeting;
+    println!("{} ({}) at {} on {} (in {}), at {}, {} (Room {})",
+        class.class,
+        class.professor,
+        time.start.hms_string(),
+        time.start.day,
+        short_duration(class.starts_in as u32),
+        time.location.campus,
+        time.location.building,
+        time.location.room
+    );
+}
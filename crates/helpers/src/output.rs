@@ -0,0 +1,80 @@
+use std::{fmt::Display, str::FromStr};
+
+use data::class::Class;
+use serde::Serialize;
+
+use super::questions::Question;
+use super::search::SearchHit;
+use super::stats::CourseStats;
+
+/// How listing commands print what they find
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Toml,
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+            OutputFormat::Toml => "toml",
+        })
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "text" | "txt" => Ok(OutputFormat::Text),
+            "json"         => Ok(OutputFormat::Json),
+            "toml"         => Ok(OutputFormat::Toml),
+            _ => Err(format!("Unknown output format \"{s}\" (expected json, toml or text)"))
+        }
+    }
+}
+
+impl OutputFormat {
+    /// Prints `value` as JSON or TOML, or calls `text` to print it for people.
+    pub fn emit<T: Serialize>(&self, value: &T, text: impl FnOnce()) {
+        let serialized = match self {
+            OutputFormat::Text => return text(),
+            OutputFormat::Json => serde_json::to_string_pretty(value).map_err(|e| e.to_string()),
+            OutputFormat::Toml => toml::to_string(value).map_err(|e| e.to_string()),
+        };
+
+        match serialized {
+            Ok(output) => println!("{}", output.trim_end()),
+            Err(e) => eprintln!("Unable to write {self} output: {e}"),
+        }
+    }
+}
+
+/// `list` output
+#[derive(Debug, Serialize)]
+pub struct ClassList<'a> {
+    pub classes: &'a [Class],
+}
+
+/// `search` output
+#[derive(Debug, Serialize)]
+pub struct SearchResults<'a> {
+    pub query: &'a str,
+    pub hits: &'a [SearchHit],
+}
+
+/// `questions` output
+#[derive(Debug, Serialize)]
+pub struct QuestionList<'a> {
+    pub questions: &'a [Question],
+}
+
+/// `stats` output
+#[derive(Debug, Serialize)]
+pub struct StatsList<'a> {
+    pub courses: &'a [CourseStats],
+}
@@ -6,15 +6,15 @@ pub fn parse_time(input: &str) -> Result<(u8, u8, u8), &str>{
     }
 
     let mut hms = split.first().unwrap().split(":");
-    let Ok(hours) = u8::from_str_radix(hms.next().unwrap(), 10) else {
+    let Ok(hours) = hms.next().unwrap().parse::<u8>() else {
         return Err("Invalid hours entry. Please try again.");
     };
 
-    let Ok(mins)  = u8::from_str_radix(hms.next().unwrap_or("0"), 10) else {
+    let Ok(mins)  = hms.next().unwrap_or("0").parse::<u8>() else {
         return Err("Invalid minutes entry. Please try again.");
     };
 
-    let Ok(secs)  = u8::from_str_radix(hms.next().unwrap_or("0"), 10) else {
+    let Ok(secs)  = hms.next().unwrap_or("0").parse::<u8>() else {
         return Err("Invalid seconds entry. Please try again.");
     };
 
@@ -0,0 +1,68 @@
+use std::io::{IsTerminal, stdin, stdout};
+use std::time::SystemTime;
+
+use dialoguer::{FuzzySelect, theme::ColorfulTheme};
+use data::{Config, class::Class};
+
+use super::io::prompt;
+use super::note::get_latest_classnote;
+
+/// Lets the user pick one of `items`, returning its index, or `None` if they cancel.
+/// Uses a fuzzy-filtering picker on a terminal, and numbered prompts otherwise.
+pub fn pick(title: &str, items: &[String]) -> Option<usize> {
+    if items.is_empty() { return None; }
+
+    if stdin().is_terminal() && stdout().is_terminal() {
+        return FuzzySelect::with_theme(&ColorfulTheme::default())
+            .with_prompt(title)
+            .items(items)
+            .default(0)
+            .interact_opt()
+            .unwrap_or_else(|e| {
+                eprintln!("Unable to show picker: {e:#}");
+                None
+            });
+    }
+
+    println!("{title}");
+    for (i, item) in items.iter().enumerate() {
+        println!("\t{}. {item}", i + 1);
+    }
+
+    loop {
+        let input = prompt!("Enter a number [1-{}], or leave blank to cancel: ", items.len());
+        if input.is_empty() { return None; }
+
+        match input.parse::<usize>() {
+            Ok(n) if (1..=items.len()).contains(&n) => return Some(n - 1),
+            _ => println!("Please enter a number between 1 and {}.", items.len())
+        }
+    }
+}
+
+/// Lets the user pick any configured class, with the most recently written-in courses first.
+pub fn pick_class(config: &Config) -> Option<Class> {
+    let mut classes = config
+        .get_classes()
+        .iter()
+        .map(|class| {
+            let modified = get_latest_classnote(config, class)
+                .and_then(|path| std::fs::metadata(&path).ok()?.modified().ok().map(|m| (path, m)));
+
+            (class, modified)
+        })
+        .collect::<Vec<(&Class, Option<_>)>>();
+
+    // most recent first, courses without notes last
+    classes.sort_by_key(|(_, modified)| std::cmp::Reverse(modified.as_ref().map(|(_, m)| *m).unwrap_or(SystemTime::UNIX_EPOCH)));
+
+    let items = classes
+        .iter()
+        .map(|(class, modified)| match modified {
+            Some((path, _)) => format!("{} ({}) - {}", class.get_name(), class.get_professor(), path.file_stem().unwrap_or_default().to_string_lossy()),
+            None => format!("{} ({}) - no notes yet", class.get_name(), class.get_professor())
+        })
+        .collect::<Vec<_>>();
+
+    return pick("Open the latest note for which class?", &items).map(|i| classes[i].0.clone());
+}
@@ -0,0 +1,82 @@
+use data::Config;
+use serde::Serialize;
+
+use super::frontmatter::strip;
+use super::review::extract_sections;
+use super::session::{Session, get_courses, get_sessions};
+
+/// Headings whose list items count as questions
+pub const QUESTION_HEADINGS: [&str; 1] = ["Questions"];
+
+/// A question left open in a session note
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Question {
+    pub session: Session,
+    pub text: String,
+}
+
+/// Text of an unchecked task item (`- [ ] ...`), `None` for checked ones or other lines
+fn unchecked_task(line: &str) -> Option<&str> {
+    let item = line.trim_start().strip_prefix(['-', '*', '+'])?.trim_start();
+    item.strip_prefix("[ ]").map(str::trim)
+}
+
+/// Open questions in a note: items of its Questions sections that aren't checked off, and unchecked tasks anywhere else.
+pub fn open_questions(content: &str) -> Vec<String> {
+    let content = strip(content);
+    let mut questions = Vec::new();
+
+    for section in extract_sections(content, &QUESTION_HEADINGS) {
+        for line in section.lines() {
+            let Some(item) = line.trim_start().strip_prefix(['-', '*', '+']) else {
+                continue;
+            };
+
+            let item = item.trim();
+            if item.starts_with("[x]") || item.starts_with("[X]") || item.is_empty() {
+                continue;
+            }
+
+            questions.push(item.strip_prefix("[ ]").unwrap_or(item).trim().to_string());
+        }
+    }
+
+    for task in content.lines().filter_map(unchecked_task) {
+        if !task.is_empty() && !questions.iter().any(|q| q == task) {
+            questions.push(task.to_string());
+        }
+    }
+
+    return questions;
+}
+
+/// Open questions across `course`'s sessions, oldest first.
+pub fn course_questions(config: &Config, course: &str) -> Vec<Question> {
+    get_sessions(&config.get_root(), course)
+        .into_iter()
+        .flat_map(|session| {
+            let content = std::fs::read_to_string(&session.note).unwrap_or_default();
+            open_questions(&content).into_iter().map(move |text| Question { session: session.clone(), text })
+        })
+        .collect()
+}
+
+/// Open questions of every course with notes, or only `course`, oldest first within each course.
+pub fn all_questions(config: &Config, course: Option<&str>) -> Vec<Question> {
+    get_courses(config)
+        .iter()
+        .filter(|c| course.is_none_or(|course| c.eq_ignore_ascii_case(course)))
+        .flat_map(|c| course_questions(config, c))
+        .collect()
+}
+
+pub fn print_questions(questions: &[Question]) {
+    if questions.is_empty() {
+        println!("No open questions.");
+        return;
+    }
+
+    for question in questions {
+        println!("{} (Class {}, {}): {}", question.session.course, question.session.number, question.session.date, question.text);
+    }
+}
@@ -0,0 +1,222 @@
+use std::{path::{Path, PathBuf}, process::{Child, Command, Stdio}};
+
+use chrono::{DateTime, Local};
+use data::recording::Recording;
+use serde::{Serialize, Deserialize};
+
+const LOG_FILE: &str = "recordings.toml";
+
+/// A recording made during a session
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RecordingEntry {
+    /// File name within the session folder
+    pub file: String,
+    pub started: DateTime<Local>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stopped: Option<DateTime<Local>>,
+}
+
+/// Every recording of a session, kept in `recordings.toml` in the session folder.
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct RecordingLog {
+    #[serde(default)]
+    pub recordings: Vec<RecordingEntry>,
+}
+
+impl RecordingLog {
+    pub fn read(session_dir: &Path) -> RecordingLog {
+        std::fs::read_to_string(session_dir.join(LOG_FILE))
+            .ok()
+            .and_then(|content| toml::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn write(&self, session_dir: &Path) -> std::io::Result<()> {
+        std::fs::write(session_dir.join(LOG_FILE), toml::to_string(self).map_err(std::io::Error::other)?)
+    }
+
+    /// The recording that was running at `time`, if any
+    pub fn at(&self, time: DateTime<Local>) -> Option<&RecordingEntry> {
+        self.recordings
+            .iter()
+            .rev()
+            .find(|r| r.started <= time && r.stopped.is_none_or(|stopped| time <= stopped))
+    }
+}
+
+/// Markdown link labelled `label` to `offset_secs` into a recording, as a media fragment players can seek to.
+pub fn recording_link(label: &str, file: &str, offset_secs: i64) -> String {
+    format!("[{label}](<{file}#t={}>)", offset_secs.max(0))
+}
+
+enum Backend {
+    Command(Child),
+    #[cfg(feature = "audio")]
+    Builtin(builtin::Recorder),
+}
+
+/// A recording in progress. Call [`ActiveRecording::stop`] to finish it.
+pub struct ActiveRecording {
+    backend: Backend,
+    pub path: PathBuf,
+    pub started: DateTime<Local>,
+}
+
+fn spawn_recorder(command: &str, path: &Path) -> Result<Child, String> {
+    let path = path.to_string_lossy();
+    let mut args = command.split_whitespace().map(|arg| arg.replace("{path}", &path)).collect::<Vec<_>>();
+    if !command.contains("{path}") {
+        args.push(path.to_string());
+    }
+
+    let Some((program, args)) = args.split_first() else {
+        return Err("The recorder command is empty.".into());
+    };
+
+    // keep the recorder's output from drawing over the editor
+    Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Unable to start recorder \"{program}\": {e:#}"))
+}
+
+/// Starts recording into the session folder, logging the recording so bookmarks can link into it.
+pub fn start_recording(config: &Recording, session_dir: &Path, session_name: &str) -> Result<ActiveRecording, String> {
+    let started = Local::now();
+    let file = format!("{session_name}-{}.{}", started.format("%H%M%S"), config.extension);
+    let path = session_dir.join(&file);
+
+    let backend = match &config.command {
+        Some(command) => Backend::Command(spawn_recorder(command, &path)?),
+        #[cfg(feature = "audio")]
+        None => Backend::Builtin(builtin::Recorder::start(&path)?),
+        #[cfg(not(feature = "audio"))]
+        None => return Err("No recorder command is configured, and classnote was built without the `audio` feature.".into()),
+    };
+
+    let mut log = RecordingLog::read(session_dir);
+    log.recordings.push(RecordingEntry { file, started, stopped: None });
+    if let Err(e) = log.write(session_dir) {
+        eprintln!("Unable to log recording: {e:#}");
+    }
+
+    return Ok(ActiveRecording { backend, path, started });
+}
+
+impl ActiveRecording {
+    pub fn file_name(&self) -> String {
+        self.path.file_name().unwrap_or_default().to_string_lossy().to_string()
+    }
+
+    /// Stops the recorder, letting it finish writing the file.
+    pub fn stop(self) -> Result<PathBuf, String> {
+        match self.backend {
+            Backend::Command(mut child) => {
+                // recorders finalize their file on an interrupt, but not when killed outright
+                #[cfg(unix)]
+                let interrupted = Command::new("kill").args(["-INT", &child.id().to_string()]).status().is_ok_and(|s| s.success());
+                #[cfg(not(unix))]
+                let interrupted = false;
+
+                if !interrupted {
+                    child.kill().map_err(|e| format!("Unable to stop recorder: {e:#}"))?;
+                }
+
+                child.wait().map_err(|e| format!("Unable to stop recorder: {e:#}"))?;
+            },
+            #[cfg(feature = "audio")]
+            Backend::Builtin(recorder) => recorder.stop()?,
+        }
+
+        if let Some(session_dir) = self.path.parent() {
+            let mut log = RecordingLog::read(session_dir);
+            if let Some(entry) = log.recordings.iter_mut().rev().find(|r| session_dir.join(&r.file) == self.path) {
+                entry.stopped = Some(Local::now());
+            }
+
+            if let Err(e) = log.write(session_dir) {
+                eprintln!("Unable to log recording: {e:#}");
+            }
+        }
+
+        return Ok(self.path);
+    }
+}
+
+#[cfg(feature = "audio")]
+mod builtin {
+    use std::{fs::File, io::BufWriter, path::Path, sync::{Arc, Mutex}};
+
+    use cpal::{SampleFormat, StreamError, traits::{DeviceTrait, HostTrait, StreamTrait}};
+    use hound::{WavSpec, WavWriter};
+
+    type Sink = Arc<Mutex<Option<WavWriter<BufWriter<File>>>>>;
+
+    /// Records the default input device to a 16-bit WAV file.
+    pub struct Recorder {
+        stream: cpal::Stream,
+        sink: Sink,
+    }
+
+    fn write(sink: &Sink, samples: impl Iterator<Item = i16>) {
+        if let Ok(mut writer) = sink.lock() && let Some(writer) = writer.as_mut() {
+            for sample in samples {
+                let _ = writer.write_sample(sample);
+            }
+        }
+    }
+
+    fn on_error(e: StreamError) {
+        eprintln!("Recording error: {e}");
+    }
+
+    impl Recorder {
+        pub fn start(path: &Path) -> Result<Recorder, String> {
+            let device = cpal::default_host().default_input_device().ok_or("No audio input device found.")?;
+            let config = device.default_input_config().map_err(|e| format!("Unable to read audio input config: {e}"))?;
+
+            let spec = WavSpec {
+                channels: config.channels(),
+                sample_rate: config.sample_rate().0,
+                bits_per_sample: 16,
+                sample_format: hound::SampleFormat::Int,
+            };
+            let sink: Sink = Arc::new(Mutex::new(Some(WavWriter::create(path, spec).map_err(|e| format!("Unable to create recording: {e}"))?)));
+
+            let writer = sink.clone();
+            let stream = match config.sample_format() {
+                SampleFormat::F32 => device.build_input_stream(
+                    &config.config(),
+                    move |data: &[f32], _: &_| write(&writer, data.iter().map(|s| (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)),
+                    on_error,
+                    None
+                ),
+                SampleFormat::I16 => device.build_input_stream(
+                    &config.config(),
+                    move |data: &[i16], _: &_| write(&writer, data.iter().copied()),
+                    on_error,
+                    None
+                ),
+                format => return Err(format!("Unsupported audio sample format {format:?}.")),
+            }.map_err(|e| format!("Unable to open audio input: {e}"))?;
+
+            stream.play().map_err(|e| format!("Unable to start recording: {e}"))?;
+
+            return Ok(Recorder { stream, sink });
+        }
+
+        pub fn stop(self) -> Result<(), String> {
+            drop(self.stream);
+
+            let writer = self.sink.lock().map_err(|_| "The recorder stopped unexpectedly.")?.take();
+            if let Some(writer) = writer {
+                writer.finalize().map_err(|e| format!("Unable to finish recording: {e}"))?;
+            }
+
+            return Ok(());
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use chrono::{Duration, NaiveDate};
+use data::{Config, class::Class, notifications::Notifications, time::{ClassTime, Time, Times}};
+
+/// What a reminder is for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Slot {
+    Class,
+    OfficeHours,
+}
+
+/// A meeting about to start
+#[derive(Debug, Clone)]
+pub struct Reminder {
+    pub class: Class,
+    pub slot: Slot,
+    pub meeting: ClassTime,
+}
+
+impl Reminder {
+    /// Title of the notification, like "MATH in 10 minutes"
+    pub fn summary(&self, minutes: u32) -> String {
+        let what = match self.slot {
+            Slot::Class => self.class.get_name().clone(),
+            Slot::OfficeHours => format!("{}'s office hours ({})", self.class.get_professor(), self.class.get_name()),
+        };
+
+        return if minutes == 0 { format!("{what} is starting") } else { format!("{what} in {minutes} minutes") };
+    }
+
+    /// When and where, like "14:00 - 15:00 at Sci 101, Main"
+    pub fn body(&self) -> String {
+        let location = &self.meeting.location;
+        let place = [format!("{} {}", location.building, location.room).trim().to_string(), location.campus.clone()]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let times = format!("{} - {}", self.meeting.start.hm_string(), self.meeting.end.hm_string());
+        return if place.is_empty() { times } else { format!("{times} at {place}") };
+    }
+
+    /// Date the meeting starts on, for a reminder sent at `time` on `today`. A reminder can come the day before, so this
+    /// is what the session's note is dated by, the way [`tick`](crate::tick::tick) dates it once the meeting starts.
+    pub fn date(&self, time: Time, today: NaiveDate) -> NaiveDate {
+        let days_until_start = (self.meeting.start.day as i64 - time.day as i64).rem_euclid(7);
+        return today + Duration::days(days_until_start);
+    }
+}
+
+fn slots(times: &Times) -> &[ClassTime] {
+    match times {
+        Times::Async => &[],
+        Times::At(times) => times,
+    }
+}
+
+/// Meetings, and office hours if enabled, whose reminder time (`before` minutes ahead of their start) is after `from`
+/// and no later than `to`, going forward around the week.
+pub fn due_reminders(config: &Config, from: Time, to: Time, settings: &Notifications) -> Vec<Reminder> {
+    let window = from.seconds_until(&to);
+    let lead = settings.before as i64 * 60;
+
+    let due = |meeting: &ClassTime| {
+        let at = from.seconds_until(&meeting.start.offset(-lead));
+        at > 0 && at <= window
+    };
+
+    let mut reminders = Vec::new();
+    for class in config.get_classes() {
+        let office_hours = if settings.office_hours { slots(class.get_office_hours()) } else { &[] };
+
+        let meetings = slots(class.get_times()).iter().map(|m| (Slot::Class, m));
+        for (slot, meeting) in meetings.chain(office_hours.iter().map(|m| (Slot::OfficeHours, m))) {
+            if due(meeting) {
+                reminders.push(Reminder { class: class.clone(), slot, meeting: meeting.clone() });
+            }
+        }
+    }
+
+    return reminders;
+}
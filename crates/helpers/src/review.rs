@@ -0,0 +1,100 @@
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use data::Config;
+
+use super::frontmatter::strip;
+use super::search::SearchFilter;
+use super::session::{Session, get_sessions};
+
+/// Headings whose sections are pulled into review guides
+pub const REVIEW_HEADINGS: [&str; 2] = ["Review", "Summary"];
+
+fn heading_level(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let text = line[level..].strip_prefix(' ')?;
+
+    (level > 0).then_some((level, text.trim()))
+}
+
+/// Bodies of every section titled one of `headings` (case-insensitive), up to the next heading of the same or higher level.
+pub fn extract_sections(content: &str, headings: &[&str]) -> Vec<String> {
+    let mut sections = Vec::new();
+    let mut current: Option<(usize, Vec<&str>)> = None;
+
+    for line in content.lines() {
+        if let Some((level, text)) = heading_level(line) {
+            if current.as_ref().is_some_and(|(open, _)| level <= *open) {
+                sections.extend(current.take().map(|(_, body)| body.join("\n")));
+            }
+
+            if current.is_none() && headings.iter().any(|h| h.eq_ignore_ascii_case(text)) {
+                current = Some((level, Vec::new()));
+                continue;
+            }
+        }
+
+        if let Some((_, body)) = current.as_mut() {
+            body.push(line);
+        }
+    }
+
+    sections.extend(current.map(|(_, body)| body.join("\n")));
+
+    return sections
+        .into_iter()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+}
+
+fn link_to(session: &Session, from_dir: &Path) -> String {
+    let target = session.note.strip_prefix(from_dir).unwrap_or(&session.note);
+    format!("[Class {} ({})](<{}>)", session.number, session.date, target.to_string_lossy())
+}
+
+/// Default location of a generated review guide, next to the course's week folders
+pub fn default_review_path(config: &Config, course: &str, from: Option<NaiveDate>, to: Option<NaiveDate>) -> PathBuf {
+    let bound = |d: Option<NaiveDate>, default: &str| d.map_or(default.to_string(), |d| d.format("%Y-%m-%d").to_string());
+
+    config.get_root().join(course).join(format!("Review-{}-to-{}.md", bound(from, "start"), bound(to, "now")))
+}
+
+/// Builds a Markdown study guide from the review sections (or whole notes) of `course`'s sessions in range,
+/// linking each section back to its note relative to `output`.
+pub fn build_review(config: &Config, course: &str, from: Option<NaiveDate>, to: Option<NaiveDate>, whole: bool, output: &Path) -> String {
+    let filter = SearchFilter { course: None, from, to };
+    let output_dir = output.parent().unwrap_or(Path::new(""));
+
+    let mut guide = format!("# {course} Review\n\n");
+    match (from, to) {
+        (Some(from), Some(to)) => guide += &format!("Sessions from {from} to {to}.\n"),
+        (Some(from), None) => guide += &format!("Sessions since {from}.\n"),
+        (None, Some(to)) => guide += &format!("Sessions up to {to}.\n"),
+        (None, None) => guide += "All sessions.\n",
+    }
+
+    let mut last_week = None;
+    for session in get_sessions(&config.get_root(), course).iter().filter(|s| filter.matches(s)) {
+        let content = std::fs::read_to_string(&session.note).unwrap_or_default();
+        let sections = if whole { vec![strip(&content).trim().to_string()] } else { extract_sections(&content, &REVIEW_HEADINGS) };
+
+        if last_week != Some(session.week) {
+            guide += &format!("\n## Week {}\n", session.week);
+            last_week = Some(session.week);
+        }
+
+        guide += &format!("\n### {}\n\n", link_to(session, output_dir));
+        if sections.iter().all(String::is_empty) {
+            guide += "_Nothing to review._\n";
+            continue;
+        }
+
+        for section in sections.iter().filter(|s| !s.is_empty()) {
+            guide += section;
+            guide += "\n\n";
+        }
+    }
+
+    return guide;
+}
@@ -0,0 +1,101 @@
+use chrono::NaiveDate;
+use data::Config;
+use serde::Serialize;
+
+use super::session::{Session, get_courses, get_sessions};
+
+// characters of context kept on either side of a match
+const SNIPPET_CONTEXT: usize = 40;
+
+#[derive(Debug, Default, Clone)]
+pub struct SearchFilter {
+    pub course: Option<String>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+impl SearchFilter {
+    pub fn matches(&self, session: &Session) -> bool {
+        self.course.as_ref().is_none_or(|c| c.eq_ignore_ascii_case(&session.course))
+            && self.from.is_none_or(|from| session.date >= from)
+            && self.to.is_none_or(|to| session.date <= to)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub session: Session,
+    /// 1-indexed line number within the note
+    pub line: usize,
+    pub snippet: String,
+}
+
+fn snippet(line: &str, at: usize, len: usize) -> String {
+    let start = line[..at].char_indices().rev().nth(SNIPPET_CONTEXT).map_or(0, |(i, _)| i);
+    let end = line[at + len..].char_indices().nth(SNIPPET_CONTEXT).map_or(line.len(), |(i, _)| at + len + i);
+
+    format!("{}{}{}",
+        if start > 0 { "..." } else { "" },
+        line[start..end].trim(),
+        if end < line.len() { "..." } else { "" }
+    )
+}
+
+/// Case-insensitive search of every session note matching `filter`, in chronological order per course.
+pub fn search(config: &Config, query: &str, filter: &SearchFilter) -> Vec<SearchHit> {
+    let query = query.to_lowercase();
+    if query.is_empty() { return Vec::new(); }
+
+    get_courses(config)
+        .iter()
+        .flat_map(|course| get_sessions(&config.get_root(), course))
+        .filter(|session| filter.matches(session))
+        .flat_map(|session| {
+            let content = std::fs::read_to_string(&session.note).unwrap_or_default();
+
+            content
+                .lines()
+                .enumerate()
+                .filter_map(|(i, line)| {
+                    let lower = line.to_lowercase();
+                    if !lower.contains(&query) { return None; }
+
+                    // lowercasing can shift byte offsets in some scripts, in which case the snippet starts at the beginning of the line
+                    let (at, len) = lower
+                        .find(&query)
+                        .filter(|&at| line.is_char_boundary(at) && line.is_char_boundary((at + query.len()).min(line.len())))
+                        .map_or((0, 0), |at| (at, query.len().min(line.len() - at)));
+
+                    Some(SearchHit { session: session.clone(), line: i + 1, snippet: snippet(line, at, len) })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Prints hits grouped by course, week and session.
+pub fn print_hits(hits: &[SearchHit]) {
+    if hits.is_empty() {
+        println!("No matches found.");
+        return;
+    }
+
+    let mut last: Option<&Session> = None;
+    for hit in hits {
+        let session = &hit.session;
+        if last.is_none_or(|l| l.course != session.course) {
+            println!("{}", session.course);
+        }
+
+        if last.is_none_or(|l| l.course != session.course || l.week != session.week) {
+            println!("  Week {}", session.week);
+        }
+
+        if last.is_none_or(|l| l.dir != session.dir) {
+            println!("    Class {} ({}) - {}", session.number, session.date, session.note.to_string_lossy());
+        }
+
+        println!("      {:>4}: {}", hit.line, hit.snippet);
+        last = Some(session);
+    }
+}
@@ -0,0 +1,99 @@
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use data::Config;
+use serde::Serialize;
+
+/// A single class meeting's folder, laid out as `<root>/<course>/Week-<week>/Class-<number>-<date>/`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Session {
+    pub course: String,
+    pub week: u16,
+    pub number: u16,
+    pub date: NaiveDate,
+    pub dir: PathBuf,
+    pub note: PathBuf,
+}
+
+impl Session {
+    /// Folder (and note) name of the session, e.g. `Class-3-2026-09-14`
+    pub fn name(&self) -> String {
+        format!("Class-{}-{}", self.number, self.date.format("%Y-%m-%d"))
+    }
+
+    /// Session of a note at `<root>/<course>/Week-<week>/Class-<number>-<date>/<name>.md`
+    pub fn from_note(root: &Path, note: &Path) -> Option<Session> {
+        let dir = note.parent()?;
+        let week_dir = dir.parent()?;
+        let course = week_dir.parent()?.strip_prefix(root).ok()?.to_string_lossy().to_string();
+        let week = week_dir.file_name()?.to_string_lossy().strip_prefix("Week-")?.parse::<u16>().ok()?;
+
+        Session::from_dir(&course, week, dir.to_path_buf())
+    }
+
+    fn from_dir(course: &str, week: u16, dir: PathBuf) -> Option<Session> {
+        let name = dir.file_name()?.to_string_lossy().to_string();
+        let (number, date) = name.strip_prefix("Class-")?.split_once('-')?;
+
+        Some(Session {
+            course: course.to_string(),
+            week,
+            number: number.parse().ok()?,
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?,
+            note: dir.join(name + ".md"),
+            dir,
+        })
+    }
+}
+
+fn subdirs(path: &Path) -> impl Iterator<Item = PathBuf> + use<> {
+    std::fs::read_dir(path)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|e| e.path())
+}
+
+/// Every session folder of `course` under `root`, in chronological order.
+pub fn get_sessions(root: &Path, course: &str) -> Vec<Session> {
+    let mut sessions = subdirs(&root.join(course))
+        .filter_map(|week_dir| {
+            let week = week_dir.file_name()?.to_string_lossy().strip_prefix("Week-")?.parse::<u16>().ok()?;
+            Some((week, week_dir))
+        })
+        .flat_map(|(week, week_dir)| subdirs(&week_dir).filter_map(move |dir| Session::from_dir(course, week, dir)))
+        .collect::<Vec<_>>();
+
+    sessions.sort_by_key(|s| (s.date, s.week, s.number));
+
+    return sessions;
+}
+
+/// Names of every course folder under the notes root, including ones no longer in the config.
+pub fn get_courses(config: &Config) -> Vec<String> {
+    let mut courses = subdirs(&config.get_root())
+        .filter(|dir| subdirs(dir).any(|week| week.file_name().is_some_and(|n| n.to_string_lossy().starts_with("Week-"))))
+        .filter_map(|dir| Some(dir.file_name()?.to_string_lossy().to_string()))
+        .collect::<Vec<_>>();
+
+    courses.sort();
+
+    return courses;
+}
+
+/// Resolves `name` to an existing course folder, ignoring case.
+pub fn find_course(config: &Config, name: &str) -> Option<String> {
+    get_courses(config).into_iter().find(|c| c.eq_ignore_ascii_case(name))
+}
+
+/// Finds a session of `course` by folder name (`Class-3-2026-09-14`), class number or date (`2026-09-14`).
+pub fn find_session(root: &Path, course: &str, query: &str) -> Option<Session> {
+    let query = query.trim();
+
+    get_sessions(root, course).into_iter().rev().find(|s|
+        s.name().eq_ignore_ascii_case(query)
+            || query.parse::<u16>().is_ok_and(|n| n == s.number)
+            || NaiveDate::parse_from_str(query, "%Y-%m-%d").is_ok_and(|d| d == s.date)
+    )
+}
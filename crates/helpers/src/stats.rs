@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use data::{Config, time::Times};
+use serde::Serialize;
+
+use super::attendance::{PLACEHOLDER, scheduled_dates};
+use super::frontmatter::strip;
+use super::note::{TEMPLATE_HEADINGS, UPCOMING};
+use super::questions::open_questions;
+use super::session::{get_courses, get_sessions};
+
+/// Notes of one `Week-N` folder
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WeekStats {
+    pub week: u16,
+    pub sessions: usize,
+    pub words: usize,
+    pub questions: usize,
+}
+
+/// Longest stretch between two consecutive sessions
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Gap {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub days: i64,
+}
+
+/// Note-taking habits in one course
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CourseStats {
+    pub course: String,
+    pub recorded: usize,
+    /// Meetings before today, if the course is configured with meeting times and a semester is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled: Option<usize>,
+    pub average_words: usize,
+    pub words_per_week: f64,
+    pub questions_per_week: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longest_gap: Option<Gap>,
+    pub weeks: Vec<WeekStats>,
+}
+
+/// Lines every new note starts with: its title, the template's headings and the upcoming or placeholder banner
+fn from_template(line: &str) -> bool {
+    let line = line.trim();
+    line.starts_with("# ") || TEMPLATE_HEADINGS.contains(&line) || line.starts_with(UPCOMING) || line == PLACEHOLDER
+}
+
+/// Words in a note, leaving out its frontmatter, what the template put there and markup like `##`, `-` and `[ ]`
+fn words(content: &str) -> usize {
+    strip(content)
+        .lines()
+        .filter(|line| !from_template(line))
+        .flat_map(str::split_whitespace)
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+/// Walks `course`'s week and session folders.
+pub fn course_stats(config: &Config, course: &str, today: NaiveDate) -> CourseStats {
+    let sessions = get_sessions(&config.get_root(), course);
+
+    let mut weeks = BTreeMap::<u16, WeekStats>::new();
+    for session in &sessions {
+        let content = std::fs::read_to_string(&session.note).unwrap_or_default();
+        let week = weeks.entry(session.week).or_insert(WeekStats { week: session.week, sessions: 0, words: 0, questions: 0 });
+
+        week.sessions += 1;
+        week.words += words(&content);
+        week.questions += open_questions(&content).len();
+    }
+
+    let scheduled = config.get_semester().and_then(|semester| {
+        let class = config.get_classes().iter().find(|c| c.get_name() == course && matches!(c.get_times(), Times::At(_)))?;
+        Some(scheduled_dates(class, semester, today).len())
+    });
+
+    let longest_gap = sessions
+        .windows(2)
+        .map(|pair| Gap { from: pair[0].date, to: pair[1].date, days: (pair[1].date - pair[0].date).num_days() })
+        .max_by_key(|gap| gap.days);
+
+    let weeks = weeks.into_values().collect::<Vec<_>>();
+    let total_words = weeks.iter().map(|w| w.words).sum::<usize>();
+    let total_questions = weeks.iter().map(|w| w.questions).sum::<usize>();
+    let per_week = |total: usize| if weeks.is_empty() { 0.0 } else { total as f64 / weeks.len() as f64 };
+
+    return CourseStats {
+        course: course.to_string(),
+        recorded: sessions.len(),
+        scheduled,
+        average_words: total_words.checked_div(sessions.len()).unwrap_or(0),
+        words_per_week: per_week(total_words),
+        questions_per_week: per_week(total_questions),
+        longest_gap,
+        weeks,
+    };
+}
+
+/// Stats of every configured class and every course with notes, or only `course`. Classes without notes yet are
+/// included, since they're the ones falling behind.
+pub fn stats(config: &Config, course: Option<&str>, today: NaiveDate) -> Vec<CourseStats> {
+    let mut courses = config.get_classes().iter().map(|c| c.get_name().clone()).collect::<Vec<_>>();
+    for folder in get_courses(config) {
+        if !courses.iter().any(|c| c.eq_ignore_ascii_case(&folder)) {
+            courses.push(folder);
+        }
+    }
+    courses.sort();
+
+    courses
+        .iter()
+        .filter(|c| course.is_none_or(|course| c.eq_ignore_ascii_case(course)))
+        .map(|c| course_stats(config, c, today))
+        .collect()
+}
+
+pub fn print_stats(stats: &[CourseStats]) {
+    if stats.is_empty() {
+        println!("No notes found.");
+        return;
+    }
+
+    let header = ["Course", "Sessions", "Avg words", "Words/week", "Questions/week", "Longest gap"].map(String::from);
+    let rows = stats.iter().map(|s| [
+        s.course.clone(),
+        match s.scheduled {
+            Some(scheduled) => format!("{}/{scheduled}", s.recorded),
+            None => s.recorded.to_string(),
+        },
+        s.average_words.to_string(),
+        format!("{:.0}", s.words_per_week),
+        format!("{:.1}", s.questions_per_week),
+        match &s.longest_gap {
+            Some(gap) => format!("{} days ({} to {})", gap.days, gap.from, gap.to),
+            None => "-".into(),
+        },
+    ]).collect::<Vec<_>>();
+
+    let widths = (0..header.len())
+        .map(|i| rows.iter().chain([&header]).map(|row| row[i].chars().count()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    for row in [&header].into_iter().chain(&rows) {
+        let cells = row.iter().zip(&widths).map(|(cell, width)| format!("{cell:<width$}")).collect::<Vec<_>>();
+        println!("{}", cells.join("  ").trim_end());
+    }
+}
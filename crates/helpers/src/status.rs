@@ -0,0 +1,128 @@
+use std::{io::Write, path::PathBuf, time::Duration};
+
+use chrono::{Local, Timelike};
+use data::{Config, class::Class, time::{ClassTime, Time, Times}};
+use serde::Serialize;
+
+use super::config::ConfigWatcher;
+use super::now::{next_class, short_duration};
+use super::output::OutputFormat;
+
+/// Status line while a class is in session
+pub const DEFAULT_TEMPLATE: &str = "{class} · {left} left · {building} {room}";
+/// Status line between classes
+pub const DEFAULT_NEXT_TEMPLATE: &str = "Next: {class} in {left} · {building} {room}";
+
+/// One status line, shaped like the JSON Waybar's custom modules read
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Status {
+    pub text: String,
+    pub tooltip: String,
+    /// `now`, `next`, or `none` when nothing is scheduled
+    pub class: String,
+}
+
+/// Templates for [`status`], where `{class}`, `{professor}`, `{campus}`, `{building}`, `{room}`, `{start}`, `{end}`
+/// and `{left}` (until the end of the class in session, or the start of the next one) are filled in.
+#[derive(Debug, Clone)]
+pub struct Templates {
+    pub now: String,
+    pub next: String,
+}
+
+impl Default for Templates {
+    fn default() -> Self {
+        Templates { now: DEFAULT_TEMPLATE.into(), next: DEFAULT_NEXT_TEMPLATE.into() }
+    }
+}
+
+/// "14 min" under an hour, otherwise like "2h 5m"
+fn left(seconds: u32) -> String {
+    if seconds < 3600 { format!("{} min", seconds.div_ceil(60)) } else { short_duration(seconds) }
+}
+
+fn fill(template: &str, class: &Class, meeting: &ClassTime, seconds_left: u32) -> String {
+    [
+        ("{class}", class.get_name().as_str()),
+        ("{professor}", class.get_professor().as_str()),
+        ("{campus}", meeting.location.campus.as_str()),
+        ("{building}", meeting.location.building.as_str()),
+        ("{room}", meeting.location.room.as_str()),
+        ("{start}", &meeting.start.hm_string()),
+        ("{end}", &meeting.end.hm_string()),
+        ("{left}", &left(seconds_left)),
+    ]
+    .iter()
+    .fold(template.to_string(), |line, (key, value)| line.replace(key, value))
+    .trim()
+    .to_string()
+}
+
+/// The class in session at `time`, or else the next one to start, filled into the matching template.
+pub fn status(config: &Config, time: Time, templates: &Templates) -> Status {
+    let current = config.get_classes()
+        .iter()
+        .flat_map(|class| match class.get_times() {
+            Times::Async => Vec::new(),
+            Times::At(times) => times.iter().map(|t| (class, t)).collect(),
+        })
+        .filter(|(_, t)| t.includes(time))
+        .min_by_key(|(_, t)| time.seconds_until(&t.end));
+
+    if let Some((class, meeting)) = current {
+        let seconds_left = time.seconds_until(&meeting.end);
+        return Status {
+            text: fill(&templates.now, class, meeting, seconds_left),
+            tooltip: format!("{} until {}", class.get_name(), meeting.end.hm_string()),
+            class: "now".into(),
+        };
+    }
+
+    match next_class(config, time) {
+        Some((class, meeting)) => Status {
+            text: fill(&templates.next, &class, &meeting, time.seconds_until(&meeting.start)),
+            tooltip: format!("{} at {} on {}", class.get_name(), meeting.start.hm_string(), meeting.start.day),
+            class: "next".into(),
+        },
+        None => Status { text: String::new(), tooltip: "No scheduled classes".into(), class: "none".into() },
+    }
+}
+
+fn emit(status: &Status, format: OutputFormat) {
+    match format {
+        // status bars read one JSON object per line
+        OutputFormat::Json => match serde_json::to_string(status) {
+            Ok(line) => println!("{line}"),
+            Err(e) => eprintln!("Unable to write json output: {e}"),
+        },
+        format => format.emit(status, || println!("{}", status.text)),
+    }
+
+    let _ = std::io::stdout().flush();
+}
+
+pub fn print_status(config: &Config, templates: &Templates, format: OutputFormat) {
+    emit(&status(config, Time::now(), templates), format);
+}
+
+/// Prints the status line whenever it changes, until interrupted. Wakes once a minute, since that's as fine as
+/// the status gets, and only rereads the config when the file has been modified.
+pub fn watch_status(mut config: Config, config_path: Option<&PathBuf>, templates: &Templates, format: OutputFormat) {
+    let mut watcher = ConfigWatcher::new(config_path);
+    let mut last = None;
+
+    loop {
+        if let Some(reloaded) = watcher.reload() {
+            config = reloaded;
+        }
+
+        let current = status(&config, Time::now(), templates);
+        if last.as_ref() != Some(&current) {
+            emit(&current, format);
+            last = Some(current);
+        }
+
+        let into_minute = Local::now().second() as u64;
+        std::thread::sleep(Duration::from_secs(60 - into_minute.min(59)));
+    }
+}
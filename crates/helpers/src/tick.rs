@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use chrono::{Duration, NaiveDate};
+use data::{Config, time::{Time, Times}};
+
+use super::note::session_note_on;
+
+/// A session note [`tick`] made sure exists
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tick {
+    pub class: String,
+    pub note: PathBuf,
+    /// `false` if the note was already there
+    pub created: bool,
+}
+
+/// Creates the session note of every meeting under way at `time`, as opening the class would, so sessions are
+/// numbered by the schedule rather than by when notes happen to be taken. Meetings running past midnight are
+/// dated by the day they started. Running it again during the same meeting does nothing.
+pub fn tick(config: &Config, time: Time, today: NaiveDate) -> Vec<Result<Tick, String>> {
+    let mut ticks = Vec::new();
+
+    for class in config.get_classes() {
+        let Times::At(times) = class.get_times() else { continue; };
+        let Some(meeting) = times.iter().find(|t| t.includes(time)) else { continue; };
+
+        let days_since_start = (time.day as i64 - meeting.start.day as i64).rem_euclid(7);
+        let date = today - Duration::days(days_since_start);
+
+        ticks.push(
+            session_note_on(config, class, date)
+                .map(|(note, existed)| Tick { class: class.get_name().clone(), note, created: !existed })
+                .map_err(|e| format!("Unable to create the {} note: {e:#}", class.get_name()))
+        );
+    }
+
+    return ticks;
+}
+
+/// Runs [`tick`] for now, printing the notes it creates and anything that went wrong, and nothing otherwise.
+pub fn print_tick(config: &Config) {
+    for tick in tick(config, Time::now(), chrono::Local::now().date_naive()) {
+        match tick {
+            Ok(Tick { created: true, note, class }) => println!("Created {class} note \"{}\".", note.to_string_lossy()),
+            Ok(_) => {},
+            Err(e) => eprintln!("{e}"),
+        }
+    }
+}
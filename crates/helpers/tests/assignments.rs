@@ -0,0 +1,30 @@
+use chrono::{Duration, Local, NaiveDate};
+use helpers::assignments::{describe_due, parse_due};
+
+fn at(date: NaiveDate, hour: u32, minute: u32) -> chrono::NaiveDateTime {
+    date.and_hms_opt(hour, minute, 0).unwrap()
+}
+
+#[test]
+fn due_dates() {
+    let day = NaiveDate::from_ymd_opt(2026, 10, 2).unwrap();
+    let today = Local::now().date_naive();
+
+    assert_eq!(parse_due("2026-10-02").unwrap(), at(day, 23, 59));
+    assert_eq!(parse_due(" 2026-10-02 09:30 ").unwrap(), at(day, 9, 30));
+    assert_eq!(parse_due("Today").unwrap(), at(today, 23, 59));
+    assert_eq!(parse_due("tomorrow").unwrap(), at(today + Duration::days(1), 23, 59));
+    assert!(parse_due("next friday").is_err());
+    assert!(parse_due("2026-13-02").is_err());
+}
+
+#[test]
+fn describes_time_left() {
+    let day = NaiveDate::from_ymd_opt(2026, 10, 2).unwrap();
+    let now = at(day, 12, 0);
+
+    assert_eq!(describe_due(at(day + Duration::days(3), 12, 0), now), "in 3 days");
+    assert_eq!(describe_due(at(day, 13, 0), now), "in 1 hour");
+    assert_eq!(describe_due(at(day, 12, 20), now), "in 20 minutes");
+    assert_eq!(describe_due(at(day - Duration::days(1), 11, 0), now), "1 day ago");
+}
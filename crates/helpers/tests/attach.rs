@@ -0,0 +1,41 @@
+mod common;
+
+use common::{class, config, session_note};
+use helpers::attach::attach;
+use tempfile::TempDir;
+
+#[test]
+fn copies_files_once_and_links_them() {
+    let root = TempDir::new().unwrap();
+    let downloads = TempDir::new().unwrap();
+    let config = config(root.path(), vec![class("CHEM", &[])]);
+    let note = session_note(root.path(), "CHEM", 1, 1, 7);
+    let dir = note.parent().unwrap();
+
+    let board = downloads.path().join("board.png");
+    let same = downloads.path().join("board copy.png");
+    let other = downloads.path().join("other").join("board.png");
+    std::fs::create_dir(other.parent().unwrap()).unwrap();
+    std::fs::write(&board, "chalk").unwrap();
+    std::fs::write(&same, "chalk").unwrap();
+    std::fs::write(&other, "eraser").unwrap();
+
+    attach(&config, &note, &[board.clone(), same, other], false).unwrap();
+    // attaching again adds nothing
+    attach(&config, &note, std::slice::from_ref(&board), false).unwrap();
+
+    assert_eq!(std::fs::read_to_string(dir.join("board.png")).unwrap(), "chalk");
+    assert_eq!(std::fs::read_to_string(dir.join("board-1.png")).unwrap(), "eraser");
+    assert!(!dir.join("board copy.png").exists());
+    assert!(board.exists());
+
+    let content = std::fs::read_to_string(&note).unwrap();
+    assert_eq!(content.matches("![board](<board.png>)").count(), 1);
+    assert_eq!(content.matches("![board-1](<board-1.png>)").count(), 1);
+
+    let slides = downloads.path().join("slides.pdf");
+    std::fs::write(&slides, "pdf").unwrap();
+    attach(&config, &note, std::slice::from_ref(&slides), true).unwrap();
+    assert!(!slides.exists());
+    assert!(std::fs::read_to_string(&note).unwrap().contains("[slides.pdf](<slides.pdf>)"));
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use common::{at, class, config, date};
+use data::{semester::{Holiday, Semester}, time::Day};
+use helpers::{attendance::{Missed, attendance, create_placeholders}, note::session_note_on, session::get_sessions};
+use tempfile::TempDir;
+
+#[test]
+fn lists_and_fills_in_missed_sessions() {
+    let root = TempDir::new().unwrap();
+    let math = class("MATH", &[Day::Monday, Day::Wednesday].map(|day| (at(day, 9, 0), at(day, 10, 0))));
+    let config = config(root.path(), vec![math.clone()]);
+
+    let semester = Semester {
+        start: date(7),
+        end: date(30),
+        holidays: vec![Holiday { date: date(7), until: None, name: "Labor Day".into() }],
+    };
+
+    for day in [9, 14, 21] {
+        session_note_on(&config, &math, date(day)).unwrap();
+    }
+
+    // the 16th is missed between two sessions that were taken
+    let report = attendance(&config, &math, &semester, date(24));
+    assert_eq!((report.scheduled, report.recorded), (5, 3));
+    assert_eq!(report.missed, vec![
+        Missed { date: date(16), week: 2, number: 3 },
+        Missed { date: date(23), week: 3, number: 5 },
+    ]);
+
+    let notes = create_placeholders(&config, &math, &report.missed).unwrap();
+    assert!(notes[0].ends_with("MATH/Week-2/Class-3-2026-09-16/Class-3-2026-09-16.md"));
+    assert!(std::fs::read_to_string(&notes[0]).unwrap().contains("Missed"));
+    assert!(attendance(&config, &math, &semester, date(24)).missed.is_empty());
+
+    // the session taken on the 21st moved up to make room, note and title included
+    let moved = root.path().join("MATH/Week-2/Class-4-2026-09-21/Class-4-2026-09-21.md");
+    assert!(std::fs::read_to_string(moved).unwrap().starts_with("# MATH - Class-4-2026-09-21"));
+
+    // the next note carries on from there
+    session_note_on(&config, &math, date(28)).unwrap();
+
+    let placed = get_sessions(root.path(), "MATH").iter().map(|s| (s.date, s.week, s.number)).collect::<Vec<_>>();
+    assert_eq!(placed, vec![
+        (date(9), 1, 1),
+        (date(14), 1, 2),
+        (date(16), 2, 3),
+        (date(21), 2, 4),
+        (date(23), 3, 5),
+        (date(28), 3, 6),
+    ]);
+}
@@ -0,0 +1,49 @@
+//! Fixtures shared by the integration tests
+// each test file only uses some of them
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use data::{Config, Location, class::Class, time::{Day, Time, Times}};
+
+pub fn at(day: Day, hour: u8, minute: u8) -> Time {
+    Time { day, hour, minute, second: 0 }
+}
+
+/// A day of September 2026
+pub fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+}
+
+pub fn room() -> Location {
+    Location { campus: "Main".into(), building: "Sci".into(), room: "101".into() }
+}
+
+/// Meetings from each start to end in [`room`], or asynchronous if there are none
+pub fn meetings(times: &[(Time, Time)]) -> Times {
+    if times.is_empty() { return Times::Async; }
+
+    Times::At(times.iter().map(|(start, end)| (*start, *end, room()).into()).collect())
+}
+
+/// A class taught by Dr. Smith at `times`, without office hours
+pub fn class(name: &str, times: &[(Time, Time)]) -> Class {
+    Class::new(name.into(), "Dr. Smith".into(), meetings(times), Times::Async)
+}
+
+/// Config keeping notes under `root`, opened in vim
+pub fn config(root: impl Into<PathBuf>, classes: Vec<Class>) -> Config {
+    Config::new(root.into(), classes, "vim".into())
+}
+
+/// Writes a bare note for session `number` of `course` in `week`, on [`date`] `day`, laid out the way sessions are filed
+pub fn session_note(root: &Path, course: &str, week: u16, number: u16, day: u32) -> PathBuf {
+    let name = format!("Class-{number}-{}", date(day));
+    let dir = root.join(course).join(format!("Week-{week}")).join(&name);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let note = dir.join(format!("{name}.md"));
+    std::fs::write(&note, format!("# {course} - {name}\n\n## Notes\n")).unwrap();
+    return note;
+}
@@ -0,0 +1,18 @@
+mod common;
+
+use common::{class, date};
+use helpers::events::{countdowns, parse_event};
+
+#[test]
+fn counts_down_to_events_within_a_month() {
+    let mut math = class("MATH", &[]);
+    assert_eq!(countdowns(&math, date(1)), None);
+
+    for (title, day) in [("Quiz", "2026-08-31"), ("Midterm", "2026-09-10 09:00"), ("Project demo", "2026-09-02"), ("Final", "2026-12-10")] {
+        math.add_event(parse_event(title.into(), day).unwrap());
+    }
+
+    assert_eq!(countdowns(&math, date(1)).as_deref(), Some("Project demo tomorrow, Midterm in 9 days"));
+    assert_eq!(countdowns(&math, date(10)).as_deref(), Some("Midterm today"));
+    assert!(parse_event("Quiz".into(), "soon").is_err());
+}
@@ -0,0 +1,34 @@
+use helpers::frontmatter::{get_field, remove_field, set_field, split, strip};
+
+#[test]
+fn set_fields_read_back() {
+    let note = "# MATH\n\n## Notes\n";
+
+    let linked = set_field(note, "week", "\"[[MATH/Week-1/Week-1|Week 1]]\"");
+    let linked = set_field(&linked, "date", "2026-09-14");
+    assert_eq!(get_field(&linked, "week"), Some("\"[[MATH/Week-1/Week-1|Week 1]]\""));
+    assert_eq!(get_field(&linked, "date"), Some("2026-09-14"));
+    assert_eq!(strip(&linked), note);
+
+    // replacing a value keeps the other properties and the body
+    let relinked = set_field(&linked, "week", "2");
+    assert_eq!(relinked, "---\nweek: 2\ndate: 2026-09-14\n---\n# MATH\n\n## Notes\n");
+    assert_eq!(get_field(&relinked, "weekday"), None);
+}
+
+#[test]
+fn notes_without_frontmatter() {
+    assert_eq!(split("# MATH\n---\n"), (None, "# MATH\n---\n"));
+    assert_eq!(split("---\n---\nbody"), (Some(""), "body"));
+    assert_eq!(get_field("course: MATH\n", "course"), None);
+}
+
+#[test]
+fn removed_fields_leave_the_rest() {
+    let note = "---\nprevious: x\nnext: y\n---\n# MATH\n";
+
+    assert_eq!(remove_field(note, "next"), "---\nprevious: x\n---\n# MATH\n");
+    assert_eq!(remove_field(&remove_field(note, "next"), "previous"), "---\n---\n# MATH\n");
+    assert_eq!(remove_field(note, "nextweek"), note);
+    assert_eq!(remove_field("# MATH\n", "next"), "# MATH\n");
+}
@@ -0,0 +1,94 @@
+use std::{path::Path, process::Command};
+
+use data::git::Git;
+use helpers::git::{SyncOutcome, commit_and_sync, init_repo};
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git").arg("-C").arg(dir).args(args).output().unwrap();
+    assert!(output.status.success(), "git {args:?} failed: {}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+fn bare_remote() -> TempDir {
+    let remote = TempDir::new().unwrap();
+    git(remote.path(), &["init", "--quiet", "--bare", "--initial-branch", "main"]);
+    remote
+}
+
+fn notes_repo(remote: &Path) -> (TempDir, Git) {
+    let root = TempDir::new().unwrap();
+    let config = Git { remote: Some(remote.to_string_lossy().to_string()), pull: true, push: true, ..Default::default() };
+
+    init_repo(root.path(), &config).unwrap();
+    git(root.path(), &["config", "user.name", "Test"]);
+    git(root.path(), &["config", "user.email", "test@example.com"]);
+
+    (root, config)
+}
+
+fn write_note(root: &Path, content: &str) {
+    let dir = root.join("MATH-2415/Week-1/Class-1-2026-01-12");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("Class-1-2026-01-12.md"), content).unwrap();
+}
+
+#[test]
+fn commits_and_pushes_to_remote() {
+    let remote = bare_remote();
+    let (root, config) = notes_repo(remote.path());
+    write_note(root.path(), "# Limits\n");
+
+    let outcome = commit_and_sync(root.path(), &root.path().join("MATH-2415"), "MATH-2415: 2026-01-12 (Class 1)", &config);
+    assert_eq!(outcome, Ok(SyncOutcome::Synced));
+    assert_eq!(git(remote.path(), &["log", "-1", "--format=%s", "main"]).trim(), "MATH-2415: 2026-01-12 (Class 1)");
+
+    let outcome = commit_and_sync(root.path(), &root.path().join("MATH-2415"), "unchanged", &config);
+    assert_eq!(outcome, Ok(SyncOutcome::Unchanged));
+}
+
+#[test]
+fn conflicting_pull_keeps_local_edits() {
+    let remote = bare_remote();
+    let (laptop, config) = notes_repo(remote.path());
+    write_note(laptop.path(), "# Limits\n");
+    commit_and_sync(laptop.path(), laptop.path(), "first", &config).unwrap();
+
+    let (desktop, _) = notes_repo(remote.path());
+    git(desktop.path(), &["pull", "--quiet", "origin", "main"]);
+
+    write_note(laptop.path(), "# Limits\nfrom the laptop\n");
+    assert_eq!(commit_and_sync(laptop.path(), laptop.path(), "laptop", &config), Ok(SyncOutcome::Synced));
+
+    write_note(desktop.path(), "# Limits\nfrom the desktop\n");
+    let outcome = commit_and_sync(desktop.path(), desktop.path(), "desktop", &config);
+    assert_eq!(outcome, Ok(SyncOutcome::Conflict(vec!["MATH-2415/Week-1/Class-1-2026-01-12/Class-1-2026-01-12.md".into()])));
+
+    let note = std::fs::read_to_string(desktop.path().join("MATH-2415/Week-1/Class-1-2026-01-12/Class-1-2026-01-12.md")).unwrap();
+    assert_eq!(note, "# Limits\nfrom the desktop\n");
+    assert_eq!(git(desktop.path(), &["log", "-1", "--format=%s"]).trim(), "desktop");
+}
+
+#[test]
+fn leaves_other_staged_files_alone() {
+    let remote = bare_remote();
+    let (root, config) = notes_repo(remote.path());
+    std::fs::write(root.path().join("todo.md"), "staged by hand\n").unwrap();
+    git(root.path(), &["add", "todo.md"]);
+    write_note(root.path(), "# Limits\n");
+
+    commit_and_sync(root.path(), &root.path().join("MATH-2415"), "notes", &config).unwrap();
+    assert_eq!(git(root.path(), &["show", "--name-only", "--format=", "HEAD"]).trim(), "MATH-2415/Week-1/Class-1-2026-01-12/Class-1-2026-01-12.md");
+    assert_eq!(git(root.path(), &["diff", "--cached", "--name-only"]).trim(), "todo.md");
+}
+
+#[test]
+fn failed_push_keeps_the_local_commit() {
+    let (root, mut config) = notes_repo(Path::new("/nonexistent/remote.git"));
+    config.pull = false;
+    write_note(root.path(), "# Limits\n");
+
+    let outcome = commit_and_sync(root.path(), root.path(), "notes", &config);
+    assert!(matches!(outcome, Ok(SyncOutcome::Unsynced(_))), "{outcome:?}");
+    assert_eq!(git(root.path(), &["log", "-1", "--format=%s"]).trim(), "notes");
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use common::{class, config, date};
+use data::{Editor, obsidian::ObsidianPath};
+use helpers::{frontmatter::{get_field, set_field}, moc::{course_moc_path, link_course}, note::session_note_on};
+use tempfile::TempDir;
+
+#[test]
+fn new_sessions_only_relink_their_neighbours() {
+    let root = TempDir::new().unwrap();
+    let chem = class("CHEM", &[]);
+    let mut config = config(root.path(), vec![chem.clone()]);
+    config.set_editor(Editor::Complex {
+        program: "obsidian".into(),
+        uri: ObsidianPath { vault: "School".into(), courses_root: root.path().into(), vault_path: Some(root.path().into()) },
+    });
+
+    let (first, _) = session_note_on(&config, &chem, date(7)).unwrap();
+    let (second, _) = session_note_on(&config, &chem, date(14)).unwrap();
+    let link = |key| get_field(&std::fs::read_to_string(&second).unwrap(), key).map(String::from);
+    assert_eq!(link("previous").as_deref(), Some("\"[[CHEM/Week-1/Class-1-2026-09-07/Class-1-2026-09-07|Class 1 (2026-09-07)]]\""));
+    assert_eq!(link("next"), None);
+
+    // the first note isn't a neighbour of the third, so it's left as it is
+    let edited = set_field(&std::fs::read_to_string(&first).unwrap(), "date", "edited");
+    std::fs::write(&first, &edited).unwrap();
+
+    let (third, _) = session_note_on(&config, &chem, date(21)).unwrap();
+    assert_eq!(std::fs::read_to_string(&first).unwrap(), edited);
+    assert!(link("next").unwrap().contains(&third.file_stem().unwrap().to_string_lossy().to_string()));
+    assert!(std::fs::read_to_string(course_moc_path(root.path(), "CHEM")).unwrap().contains("Class 3 (2026-09-21)"));
+
+    // relinking the whole course puts it back
+    link_course(&config, "CHEM").unwrap();
+    assert_eq!(get_field(&std::fs::read_to_string(&first).unwrap(), "date"), Some("2026-09-07"));
+
+    // with the third session gone, the second has no next note and loses the key rather than keeping an empty one
+    std::fs::remove_dir_all(third.parent().unwrap()).unwrap();
+    link_course(&config, "CHEM").unwrap();
+    assert_eq!(link("next"), None);
+    assert!(!std::fs::read_to_string(&second).unwrap().contains("next:"));
+}
@@ -0,0 +1,18 @@
+mod common;
+
+use common::{class, config, date};
+use helpers::note::session_note_on;
+use tempfile::TempDir;
+
+#[test]
+fn first_note_under_a_missing_root_is_in_week_one() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("notes");
+    let chem = class("CHEM", &[]);
+    let config = config(&root, vec![chem.clone()]);
+
+    let (note, existed) = session_note_on(&config, &chem, date(7)).unwrap();
+    assert!(!existed);
+    assert_eq!(note, root.join("CHEM/Week-1/Class-1-2026-09-07/Class-1-2026-09-07.md"));
+    assert!(!root.join("CHEM/Week-0").exists());
+}
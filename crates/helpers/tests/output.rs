@@ -0,0 +1,36 @@
+mod common;
+
+use common::{at, class};
+use data::{Config, time::{Day, Time}};
+use helpers::{now::{next, now}, output::OutputFormat};
+
+fn config() -> Config {
+    common::config("/notes", vec![class("MATH", &[(at(Day::Monday, 9, 0), at(Day::Monday, 10, 0))])])
+}
+
+#[test]
+fn next_counts_forward_into_next_week() {
+    let next = next(&config(), at(Day::Monday, 11, 0)).next.unwrap();
+
+    assert_eq!(next.class, "MATH");
+    assert_eq!(next.starts_in, 7 * 86400 - 2 * 3600);
+    assert_eq!(next.ends_in, next.starts_in + 3600);
+}
+
+#[test]
+fn listings_serialize_as_json_and_toml() {
+    let now = now(&config(), Time { minute: 30, ..at(Day::Monday, 9, 0) });
+    assert_eq!((now.classes[0].starts_in, now.classes[0].ends_in), (-1800, 1800));
+
+    let json: serde_json::Value = serde_json::from_str(&serde_json::to_string(&now).unwrap()).unwrap();
+    assert_eq!(json["classes"][0]["meeting"]["start"]["day"], "Monday");
+
+    let toml = toml::to_string(&now).unwrap();
+    assert!(toml.contains("class = \"MATH\""), "{toml}");
+
+    // nothing scheduled leaves `next` out of TOML and null in JSON
+    let empty = next(&Config::default(), at(Day::Monday, 9, 0));
+    assert!(toml::to_string(&empty).is_ok());
+    assert!(serde_json::to_value(&empty).unwrap()["next"].is_null());
+    assert_eq!("json".parse::<OutputFormat>(), Ok(OutputFormat::Json));
+}
@@ -0,0 +1,13 @@
+use helpers::questions::open_questions;
+
+#[test]
+fn questions_section_items_and_unchecked_tasks() {
+    let note = "---\ncourse: MATH\n---\n# MATH - Class-1-2026-09-14\n\n## Notes\n\n- limits\n- [ ] ask about epsilon-delta\n- [x] read 1.2\n\n## Questions\n\n- why is 0! = 1?\n- [x] what's a limit?\n- [ ] ask about epsilon-delta\n\n## Review\n\n- [ ] \n";
+
+    assert_eq!(open_questions(note), vec!["why is 0! = 1?", "ask about epsilon-delta"]);
+}
+
+#[test]
+fn no_questions() {
+    assert!(open_questions("# Title\n\n## Notes\n\n- [x] done\n* plain item\n").is_empty());
+}
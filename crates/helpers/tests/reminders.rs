@@ -0,0 +1,39 @@
+mod common;
+
+use common::{at, class, date, meetings};
+use data::{Config, notifications::Notifications, time::Day};
+use helpers::reminders::{Slot, due_reminders};
+
+fn config() -> Config {
+    let mut math = class("MATH", &[(at(Day::Monday, 0, 5), at(Day::Monday, 1, 0))]);
+    math.set_office_hours(meetings(&[(at(Day::Wednesday, 14, 0), at(Day::Wednesday, 15, 0))]));
+
+    common::config("/notes", vec![math])
+}
+
+#[test]
+fn reminds_once_per_window() {
+    let settings = Notifications::default();
+
+    // ten minutes before 00:05 on Monday is 23:55 on Sunday
+    let due = due_reminders(&config(), at(Day::Sunday, 23, 54), at(Day::Sunday, 23, 55), &settings);
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].slot, Slot::Class);
+    assert_eq!(due[0].summary(settings.before), "MATH in 10 minutes");
+    assert_eq!(due[0].body(), "00:05 - 01:00 at Sci 101, Main");
+    // its note is the Monday's, not the Sunday the reminder came on
+    assert_eq!(due[0].date(at(Day::Sunday, 23, 55), date(6)), date(7));
+
+    // the next window doesn't repeat it
+    assert!(due_reminders(&config(), at(Day::Sunday, 23, 55), at(Day::Sunday, 23, 56), &settings).is_empty());
+}
+
+#[test]
+fn office_hours_can_be_left_out() {
+    let (from, to) = (at(Day::Wednesday, 13, 45), at(Day::Wednesday, 13, 50));
+
+    let due = due_reminders(&config(), from, to, &Notifications::default());
+    assert_eq!(due[0].summary(10), "Dr. Smith's office hours (MATH) in 10 minutes");
+
+    assert!(due_reminders(&config(), from, to, &Notifications { office_hours: false, ..Default::default() }).is_empty());
+}
@@ -0,0 +1,15 @@
+use helpers::review::{REVIEW_HEADINGS, extract_sections};
+
+#[test]
+fn sections_end_at_the_next_heading_of_the_same_level() {
+    let note = "# MATH\n\n## Notes\n\n- limits\n\n## Review\n\n- epsilon-delta\n\n### Examples\n\n- 1/x\n\n## Questions\n\n- why?\n\n## summary\n\nLimits are local.\n";
+
+    assert_eq!(extract_sections(note, &REVIEW_HEADINGS), vec!["- epsilon-delta\n\n### Examples\n\n- 1/x", "Limits are local."]);
+}
+
+#[test]
+fn empty_and_missing_sections() {
+    assert!(extract_sections("# MATH\n\n## Review\n\n## Notes\n\n- limits\n", &REVIEW_HEADINGS).is_empty());
+    assert!(extract_sections("no headings here\n#Review\n", &REVIEW_HEADINGS).is_empty());
+    assert_eq!(extract_sections("## Review\nlast section", &REVIEW_HEADINGS), vec!["last section"]);
+}
@@ -0,0 +1,31 @@
+mod common;
+
+use common::{class, config, date, session_note};
+use helpers::search::{SearchFilter, search};
+use tempfile::TempDir;
+
+#[test]
+fn filters_by_course_and_date() {
+    let root = TempDir::new().unwrap();
+    let config = config(root.path(), vec![class("MATH", &[]), class("CHEM", &[])]);
+
+    for (course, number, day) in [("MATH", 1, 7), ("MATH", 2, 14), ("CHEM", 1, 8)] {
+        let note = session_note(root.path(), course, 1, number, day);
+        let content = std::fs::read_to_string(&note).unwrap();
+        std::fs::write(&note, content + "\nThe Derivative of a sum\n").unwrap();
+    }
+
+    let found = |filter: SearchFilter| search(&config, "derivative", &filter)
+        .into_iter()
+        .map(|hit| (hit.session.course, hit.session.date.to_string()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(found(SearchFilter::default()), [("CHEM".into(), "2026-09-08".into()), ("MATH".into(), "2026-09-07".into()), ("MATH".into(), "2026-09-14".into())]);
+    assert_eq!(found(SearchFilter { course: Some("math".into()), ..Default::default() }).len(), 2);
+    assert_eq!(found(SearchFilter { from: Some(date(8)), to: Some(date(13)), ..Default::default() }), [("CHEM".into(), "2026-09-08".into())]);
+    assert!(found(SearchFilter { course: Some("CHEM".into()), from: Some(date(9)), ..Default::default() }).is_empty());
+
+    let hits = search(&config, "DERIVATIVE", &SearchFilter::default());
+    assert_eq!(hits[0].snippet, "The Derivative of a sum");
+    assert!(search(&config, "", &SearchFilter::default()).is_empty());
+}
@@ -0,0 +1,124 @@
+use data::{Config, Editor, time::{Day, Times}};
+use helpers::{config::setup_wizard, io::Scripted};
+use tempfile::TempDir;
+
+/// Start day, end day, start time, end time and room
+type Meeting = (Day, Day, (u8, u8, u8), (u8, u8, u8), String);
+
+fn meetings(config: &Config, class: &str) -> Vec<Meeting> {
+    let class = config.get_classes().iter().find(|c| c.get_name() == class).unwrap();
+    match class.get_times() {
+        Times::Async => Vec::new(),
+        Times::At(times) => times.iter().map(|t| (t.start.day, t.end.day, t.start.get_hms(), t.end.get_hms(), t.location.room.clone())).collect(),
+    }
+}
+
+#[test]
+fn new_config_from_scratch() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("notes");
+
+    let mut script = Scripted::new([
+        "n", // build from an existing directory?
+        "y", // create a new config?
+        root.to_str().unwrap(),
+        "MATH",
+        "Dr. Smith",
+        "Q", // not a day, asked again
+        "M/W",
+        "9:00",
+        "10:15",
+        "Main",
+        "Sci",
+        "101",
+        "", // same time on Wednesday
+        "n", // office hours?
+        "y", // another class?
+        "PHYS",
+        "",  // professor is required, asked again
+        "Dr. Jones",
+        "", // asynchronous
+        "y", // office hours?
+        "F",
+        "1:00 PM",
+        "2:00 PM",
+        "Main",
+        "Phys",
+        "210",
+        "n", // another class?
+        "nvim +{line} {path}",
+        "y", // editor waits?
+    ]);
+
+    let config = setup_wizard(&mut script, None).expect("the wizard should make a config");
+    assert_eq!(script.remaining(), 0);
+
+    assert!(root.is_dir(), "the notes folder should be created");
+    assert_eq!(config.get_root(), root);
+    assert_eq!(config.get_classes().iter().map(|c| c.get_name().as_str()).collect::<Vec<_>>(), ["MATH", "PHYS"]);
+
+    assert_eq!(meetings(&config, "MATH"), [
+        (Day::Monday, Day::Monday, (9, 0, 0), (10, 15, 0), "101".to_string()),
+        (Day::Wednesday, Day::Wednesday, (9, 0, 0), (10, 15, 0), "101".to_string()),
+    ]);
+    assert!(meetings(&config, "PHYS").is_empty());
+
+    let phys = &config.get_classes()[1];
+    assert_eq!(phys.get_professor(), "Dr. Jones");
+    let Times::At(office_hours) = phys.get_office_hours() else { panic!("PHYS should have office hours") };
+    assert_eq!((office_hours[0].start.day, office_hours[0].start.get_hms()), (Day::Friday, (13, 0, 0)));
+
+    assert!(matches!(config.get_editor(), Editor::Custom { command, wait: Some(true) } if command == "nvim +{line} {path}"));
+
+    // what the wizard made is what gets saved and read back
+    let reread: Config = toml::from_str(&config.serialize()).unwrap();
+    assert_eq!(reread.serialize(), config.serialize());
+}
+
+#[test]
+fn config_from_existing_folders() {
+    let dir = TempDir::new().unwrap();
+    std::fs::create_dir(dir.path().join("CHEM")).unwrap();
+    std::fs::create_dir(dir.path().join("BIO")).unwrap();
+    std::fs::write(dir.path().join("todo.md"), "").unwrap();
+
+    let mut script = Scripted::new([
+        "y", // build from an existing directory?
+        dir.path().to_str().unwrap(),
+        "Dr. Bell", // BIO, folders are taken in order
+        "",
+        "n",
+        "Dr. Curie", // CHEM
+        "Tu/Th",
+        "22:00",
+        "1:00",
+        "y", // spans two days
+        "North",
+        "Lab",
+        "B2",
+        "y", // same time on Thursday
+        "n",
+        "y", // set a notes path?
+        dir.path().to_str().unwrap(),
+        "", // keep $EDITOR
+    ]);
+
+    let config = setup_wizard(&mut script, Some("vim".into())).expect("the wizard should make a config");
+    assert_eq!(script.remaining(), 0);
+
+    assert_eq!(config.get_root(), dir.path());
+    assert_eq!(config.get_classes().iter().map(|c| c.get_name().as_str()).collect::<Vec<_>>(), ["BIO", "CHEM"]);
+    assert_eq!(meetings(&config, "CHEM"), [
+        (Day::Tuesday, Day::Wednesday, (22, 0, 0), (1, 0, 0), "B2".to_string()),
+        (Day::Thursday, Day::Friday, (22, 0, 0), (1, 0, 0), "B2".to_string()),
+    ]);
+    assert!(matches!(config.get_editor(), Editor::Simple(program) if program == "vim"));
+}
+
+#[test]
+fn declining_setup() {
+    let mut script = Scripted::new(["n", "n"]);
+
+    assert!(setup_wizard(&mut script, None).is_none());
+    assert_eq!(script.remaining(), 0);
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use common::{at, class, config, date};
+use data::{semester::Semester, time::Day};
+use helpers::{note::session_note_on, stats::{course_stats, stats}};
+use tempfile::TempDir;
+
+#[test]
+fn counts_words_questions_and_gaps() {
+    let root = TempDir::new().unwrap();
+    let chem = class("CHEM", &[]);
+    let config = config(root.path(), vec![chem.clone()]);
+
+    let (first, _) = session_note_on(&config, &chem, date(1)).unwrap();
+    std::fs::write(&first, "---\ntags: [chem]\n---\none two three\n\n## Questions\n- Why?\n").unwrap();
+    let (second, _) = session_note_on(&config, &chem, date(3)).unwrap();
+    std::fs::write(&second, "four five - [ ] check\n").unwrap();
+    // left as the template made it, so it adds no words
+    session_note_on(&config, &chem, date(10)).unwrap();
+
+    let stats = course_stats(&config, "CHEM", date(20));
+    assert_eq!(stats.recorded, 3);
+    assert_eq!(stats.scheduled, None);
+    assert_eq!(stats.weeks.iter().map(|w| w.words).sum::<usize>(), 5 + 3);
+    assert_eq!(stats.weeks.iter().map(|w| w.questions).sum::<usize>(), 1);
+    assert_eq!(stats.longest_gap.map(|g| (g.from, g.to, g.days)), Some((date(3), date(10), 7)));
+}
+
+#[test]
+fn classes_without_notes_are_listed() {
+    let root = TempDir::new().unwrap();
+    let chem = class("CHEM", &[]);
+    let math = class("MATH", &[(at(Day::Monday, 9, 0), at(Day::Monday, 10, 0))]);
+    let mut config = config(root.path(), vec![math, chem.clone()]);
+    config.set_semester(Some(Semester { start: date(7), end: date(30), holidays: Vec::new() }));
+
+    session_note_on(&config, &chem, date(1)).unwrap();
+
+    let all = stats(&config, None, date(22));
+    assert_eq!(all.iter().map(|s| (s.course.as_str(), s.recorded, s.scheduled)).collect::<Vec<_>>(), [("CHEM", 1, None), ("MATH", 0, Some(3))]);
+    assert_eq!(stats(&config, Some("math"), date(22)).len(), 1);
+}
@@ -0,0 +1,25 @@
+mod common;
+
+use common::{at, class};
+use data::{Config, time::{Day, Time}};
+use helpers::status::{Templates, status};
+
+fn config() -> Config {
+    common::config("/notes", vec![class("MATH-2415", &[(at(Day::Monday, 14, 0), at(Day::Monday, 15, 0))])])
+}
+
+#[test]
+fn in_session_and_between_classes() {
+    let templates = Templates::default();
+
+    let now = status(&config(), Time { second: 10, ..at(Day::Monday, 14, 46) }, &templates);
+    assert_eq!((now.text.as_str(), now.class.as_str()), ("MATH-2415 · 14 min left · Sci 101", "now"));
+
+    let next = status(&config(), at(Day::Monday, 11, 55), &templates);
+    assert_eq!((next.text.as_str(), next.class.as_str()), ("Next: MATH-2415 in 2h 5m · Sci 101", "next"));
+
+    let custom = Templates { now: "{class} until {end}".into(), ..Templates::default() };
+    assert_eq!(status(&config(), at(Day::Monday, 14, 30), &custom).text, "MATH-2415 until 15:00");
+
+    assert_eq!(status(&Config::default(), at(Day::Monday, 14, 30), &templates).class, "none");
+}
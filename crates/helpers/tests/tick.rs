@@ -0,0 +1,41 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::{at, class};
+use data::{Config, time::Day};
+use helpers::tick::tick;
+use tempfile::TempDir;
+
+fn config(root: &TempDir) -> Config {
+    let math = class("MATH", &[(at(Day::Monday, 9, 0), at(Day::Monday, 10, 0))]);
+    let astro = class("ASTRO", &[(at(Day::Sunday, 23, 0), at(Day::Monday, 1, 0))]);
+
+    common::config(root.path(), vec![math, astro])
+}
+
+#[test]
+fn creates_the_note_once_per_meeting() {
+    let root = TempDir::new().unwrap();
+    let monday = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+
+    assert!(tick(&config(&root), at(Day::Monday, 8, 59), monday).is_empty());
+
+    let first = tick(&config(&root), at(Day::Monday, 9, 1), monday);
+    let created = first[0].as_ref().unwrap();
+    assert!(created.created);
+    assert!(created.note.ends_with("MATH/Week-1/Class-1-2026-10-19/Class-1-2026-10-19.md"));
+    assert!(std::fs::read_to_string(&created.note).unwrap().starts_with("# MATH - Class-1-2026-10-19"));
+
+    let again = tick(&config(&root), at(Day::Monday, 9, 30), monday);
+    assert_eq!(again[0].as_ref().unwrap().note, created.note);
+    assert!(!again[0].as_ref().unwrap().created);
+}
+
+#[test]
+fn overnight_meetings_keep_their_start_date() {
+    let root = TempDir::new().unwrap();
+    let monday = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+
+    let ticks = tick(&config(&root), at(Day::Monday, 0, 30), monday);
+    assert!(ticks[0].as_ref().unwrap().note.ends_with("ASTRO/Week-1/Class-1-2026-10-18/Class-1-2026-10-18.md"));
+}